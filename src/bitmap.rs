@@ -0,0 +1,349 @@
+use crate::{boundaries::Boundary, maze::{Coordinates, Rectangle}};
+
+pub struct BitmapError {
+    pub message: String,
+}
+
+// A monochrome image that the maze is cut out of, a point is inside when it lands on a set pixel
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    // Row by row from the top left
+    pixels: Vec<bool>,
+    // Where the bitmap is drawn, each pixel covers an equal part of it
+    pub area: Rectangle,
+}
+
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 7;
+
+// Each row of a glyph is read from the highest of its five bits on the left
+const FONT: &[(char, [u8; GLYPH_HEIGHT])] = &[
+    ('A', [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('B', [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110]),
+    ('C', [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110]),
+    ('D', [0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110]),
+    ('E', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111]),
+    ('F', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('G', [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111]),
+    ('H', [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('I', [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('J', [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100]),
+    ('K', [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001]),
+    ('L', [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111]),
+    ('M', [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001]),
+    ('N', [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001]),
+    ('O', [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('P', [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('Q', [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101]),
+    ('R', [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001]),
+    ('S', [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
+    ('T', [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+    ('U', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('V', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+    ('W', [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010]),
+    ('X', [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001]),
+    ('Y', [0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100]),
+    ('Z', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111]),
+    ('0', [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+    ('1', [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('2', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+    ('3', [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+    ('4', [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+    ('5', [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+    ('6', [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+    ('7', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+    ('8', [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+    ('9', [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+    ('!', [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100]),
+    ('?', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100]),
+    ('.', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100]),
+    (',', [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000]),
+    ('\'', [0b00100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000]),
+    (':', [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000]),
+    ('-', [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+    ('+', [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000]),
+    ('=', [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000]),
+    ('/', [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000]),
+    ('(', [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010]),
+    (')', [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000]),
+    ('&', [0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101]),
+    ('*', [0b00000, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0b00000]),
+    ('#', [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010]),
+];
+
+impl Bitmap {
+    // Starts out covering one unit per pixel from the origin, use fit to place it
+    pub fn new(width: usize, height: usize, pixels: Vec<bool>) -> Result<Bitmap, BitmapError> {
+        if width == 0 || height == 0 {
+            return Err(BitmapError {
+                message: format!("Bitmap is empty, it is {} by {} pixels", width, height),
+            });
+        }
+
+        if pixels.len() != width * height {
+            return Err(BitmapError {
+                message: format!("Bitmap is {} by {} but has {} pixels", width, height, pixels.len()),
+            });
+        }
+
+        Ok(Bitmap {
+            width,
+            height,
+            pixels,
+            area: Rectangle { x: 0.0, y: 0.0, width: width as f64, height: height as f64 },
+        })
+    }
+
+    // One bit per pixel with the first pixel in the highest bit, and each row starting on a new byte, like a PBM file
+    pub fn from_bytes(width: usize, height: usize, bytes: &[u8]) -> Result<Bitmap, BitmapError> {
+        let row_bytes = width.div_ceil(8);
+        if bytes.len() < row_bytes * height {
+            return Err(BitmapError {
+                message: format!("A {} by {} bitmap needs {} bytes but there are {}", width, height, row_bytes * height, bytes.len()),
+            });
+        }
+
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| bytes[y * row_bytes + x / 8] & (0x80 >> (x % 8)) != 0))
+            .collect();
+        Bitmap::new(width, height, pixels)
+    }
+
+    // Four bytes per pixel, as a decoded PNG comes out. Dark pixels are set, and transparent ones never are.
+    pub fn from_rgba(width: usize, height: usize, rgba: &[u8]) -> Result<Bitmap, BitmapError> {
+        if rgba.len() != width * height * 4 {
+            return Err(BitmapError {
+                message: format!("A {} by {} image needs {} bytes but there are {}", width, height, width * height * 4, rgba.len()),
+            });
+        }
+
+        let pixels = rgba.chunks(4)
+            .map(|pixel| {
+                let luminance = (299 * pixel[0] as u32 + 587 * pixel[1] as u32 + 114 * pixel[2] as u32) / 1000;
+                pixel[3] >= 128 && luminance < 128
+            })
+            .collect();
+        Bitmap::new(width, height, pixels)
+    }
+
+    // A line of text in the built in font, letters it has no glyph for are left blank.
+    // The strokes are thickened by a pixel so diagonal ones stay joined up, and the underline joins the letters
+    // to each other so that they make one maze rather than a maze per letter. Strokes that don't reach down to
+    // the underline, like dots and dashes, are dropped down to it on a stem.
+    pub fn text(text: &str, underline: bool) -> Bitmap {
+        let letters: Vec<char> = text.chars().map(|letter| letter.to_ascii_uppercase()).collect();
+        let advance = GLYPH_WIDTH + 2;
+        let width = (letters.len() * advance).max(2) - 1;
+        let height = GLYPH_HEIGHT + underline as usize;
+        let mut pixels = vec![false; width * height];
+
+        for (index, letter) in letters.iter().enumerate() {
+            let glyph = match FONT.iter().find(|(character, _)| character == letter) {
+                Some((_, glyph)) => glyph,
+                None => continue,
+            };
+            for (y, row) in glyph.iter().enumerate() {
+                for x in 0..GLYPH_WIDTH {
+                    if row & (1 << (GLYPH_WIDTH - 1 - x)) != 0 {
+                        let left = index * advance + x;
+                        pixels[y * width + left] = true;
+                        pixels[y * width + left + 1] = true;
+                    }
+                }
+            }
+        }
+
+        let mut bitmap = Bitmap { width, height, pixels, area: Rectangle { x: 0.0, y: 0.0, width: width as f64, height: height as f64 } };
+        if underline {
+            bitmap.pixels[GLYPH_HEIGHT * width..].fill(true);
+            for piece in bitmap.pieces() {
+                if piece.iter().all(|&(_, y)| y < GLYPH_HEIGHT) {
+                    bitmap.drop_stem(&piece);
+                }
+            }
+        }
+
+        bitmap
+    }
+
+    // Groups of set pixels joined up through their sides
+    fn pieces(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut seen = vec![false; self.pixels.len()];
+        let mut pieces = Vec::new();
+
+        for start in 0..self.pixels.len() {
+            if !self.pixels[start] || seen[start] {
+                continue;
+            }
+            seen[start] = true;
+
+            let mut piece = Vec::new();
+            let mut stack = vec![(start % self.width, start / self.width)];
+            while let Some((x, y)) = stack.pop() {
+                piece.push((x, y));
+                let neighbors = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+                for (x, y) in neighbors {
+                    if self.get(x, y) && !seen[y * self.width + x] {
+                        seen[y * self.width + x] = true;
+                        stack.push((x, y));
+                    }
+                }
+            }
+            pieces.push(piece);
+        }
+
+        pieces
+    }
+
+    // Fills a stroke's width of pixels from the middle of the bottom of the piece down to the bottom row
+    fn drop_stem(&mut self, piece: &[(usize, usize)]) {
+        let bottom = piece.iter().map(|&(_, y)| y).max().unwrap_or(0);
+        let mut lowest: Vec<usize> = piece.iter().filter(|&&(_, y)| y == bottom).map(|&(x, _)| x).collect();
+        lowest.sort();
+        let left = lowest[(lowest.len() - 1) / 2];
+
+        for y in bottom..self.height {
+            for x in left..(left + 2).min(self.width) {
+                self.pixels[y * self.width + x] = true;
+            }
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    pub fn is_blank(&self) -> bool {
+        !self.pixels.contains(&true)
+    }
+
+    // Scales the bitmap up or down to be as large as will fit in the rectangle, keeping the pixels square, and centers it
+    pub fn fit(self, rectangle: Rectangle) -> Bitmap {
+        let pixel_size = (rectangle.width / self.width as f64).min(rectangle.height / self.height as f64);
+        let width = self.width as f64 * pixel_size;
+        let height = self.height as f64 * pixel_size;
+        Bitmap {
+            area: Rectangle {
+                x: rectangle.x + (rectangle.width - width) / 2.0,
+                y: rectangle.y + (rectangle.height - height) / 2.0,
+                width,
+                height,
+            },
+            ..self
+        }
+    }
+}
+
+impl Boundary for Bitmap {
+    fn contains(&self, point: Coordinates) -> bool {
+        let x = (point.x - self.area.x) / self.area.width * self.width as f64;
+        let y = (point.y - self.area.y) / self.area.height * self.height as f64;
+        x >= 0.0 && y >= 0.0 && self.get(x as usize, y as usize)
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        self.area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(bitmap: &Bitmap) -> Vec<String> {
+        (0..bitmap.height)
+            .map(|y| (0..bitmap.width).map(|x| if bitmap.get(x, y) { '#' } else { '.' }).collect())
+            .collect()
+    }
+
+    #[test]
+    fn from_bytes_reads_the_highest_bit_first() {
+        // Ten pixels wide, so each row takes two bytes and the last six bits are padding
+        let bitmap = Bitmap::from_bytes(10, 2, &[0b1010_0000, 0b0011_1111, 0b0000_0001, 0b1000_0000]).ok().unwrap();
+        assert_eq!(rows(&bitmap), vec!["#.#.......", ".......##."]);
+    }
+
+    #[test]
+    fn from_bytes_needs_every_row() {
+        assert!(Bitmap::from_bytes(10, 2, &[0, 0, 0]).is_err());
+        assert!(Bitmap::from_bytes(0, 2, &[]).is_err());
+    }
+
+    #[test]
+    fn from_rgba_sets_dark_opaque_pixels() {
+        let rgba = [
+            0, 0, 0, 255,       // Black
+            255, 255, 255, 255, // White
+            0, 0, 0, 127,       // Black but mostly transparent
+            0, 0, 0, 128,       // Black and just opaque enough
+            127, 127, 127, 255, // Just dark enough
+            128, 128, 128, 255, // Just too light
+            255, 0, 0, 255,     // Red is dark, with a luminance of 76
+            0, 255, 0, 255,     // Green is light, with a luminance of 149
+        ];
+        let bitmap = Bitmap::from_rgba(8, 1, &rgba).ok().unwrap();
+        assert_eq!(rows(&bitmap), vec!["#..##.#."]);
+    }
+
+    #[test]
+    fn from_rgba_needs_four_bytes_a_pixel() {
+        assert!(Bitmap::from_rgba(2, 1, &[0; 7]).is_err());
+    }
+
+    #[test]
+    fn text_thickens_letters_and_underlines_them() {
+        let bitmap = Bitmap::text("hi", true);
+        assert_eq!(rows(&bitmap), vec![
+            "##..##..####.",
+            "##..##...##..",
+            "##..##...##..",
+            "######...##..",
+            "##..##...##..",
+            "##..##...##..",
+            "##..##..####.",
+            "#############",
+        ]);
+    }
+
+    #[test]
+    fn text_joins_every_glyph_to_the_underline() {
+        for (letter, _) in FONT {
+            let bitmap = Bitmap::text(&letter.to_string(), true);
+            assert_eq!(bitmap.pieces().len(), 1, "{}", letter);
+        }
+    }
+
+    #[test]
+    fn text_drops_a_dash_to_the_underline() {
+        let bitmap = Bitmap::text("-", true);
+        assert_eq!(rows(&bitmap), vec![
+            "......",
+            "......",
+            "......",
+            "######",
+            "..##..",
+            "..##..",
+            "..##..",
+            "######",
+        ]);
+    }
+
+    #[test]
+    fn text_leaves_unknown_letters_blank() {
+        let bitmap = Bitmap::text("~", false);
+        assert_eq!((bitmap.width, bitmap.height), (6, 7));
+        assert!(bitmap.is_blank());
+    }
+
+    #[test]
+    fn fit_centers_square_pixels() {
+        let bitmap = Bitmap::new(2, 1, vec![true, false]).ok().unwrap()
+            .fit(Rectangle { x: 10.0, y: 10.0, width: 100.0, height: 100.0 });
+        assert_eq!(bitmap.area, Rectangle { x: 10.0, y: 35.0, width: 100.0, height: 50.0 });
+        assert!(bitmap.contains(Coordinates { x: 30.0, y: 40.0 }));
+        assert!(!bitmap.contains(Coordinates { x: 80.0, y: 40.0 }));
+        assert!(!bitmap.contains(Coordinates { x: 30.0, y: 30.0 }));
+    }
+}
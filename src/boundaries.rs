@@ -0,0 +1,164 @@
+use std::f64::consts::PI;
+
+use crate::maze::{Coordinates, Rectangle};
+
+// The outline that a maze is cut out of
+pub trait Boundary {
+    fn contains(&self, point: Coordinates) -> bool;
+    // The smallest rectangle around the whole shape, which wrapped edges are measured from
+    fn bounding_box(&self) -> Rectangle;
+}
+
+impl Boundary for Rectangle {
+    fn contains(&self, point: Coordinates) -> bool {
+        Rectangle::contains(*self, point)
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        *self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle {
+    pub center: Coordinates,
+    pub radius: f64,
+}
+
+impl Boundary for Circle {
+    fn contains(&self, point: Coordinates) -> bool {
+        (point - self.center).length() <= self.radius
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle {
+            x: self.center.x - self.radius,
+            y: self.center.y - self.radius,
+            width: self.radius * 2.0,
+            height: self.radius * 2.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellipse {
+    pub center: Coordinates,
+    pub radius_x: f64,
+    pub radius_y: f64,
+}
+
+impl Boundary for Ellipse {
+    fn contains(&self, point: Coordinates) -> bool {
+        let distance = point - self.center;
+        (distance.x / self.radius_x).powi(2) + (distance.y / self.radius_y).powi(2) <= 1.0
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle {
+            x: self.center.x - self.radius_x,
+            y: self.center.y - self.radius_y,
+            width: self.radius_x * 2.0,
+            height: self.radius_y * 2.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RegularPolygon {
+    pub center: Coordinates,
+    // Distance from the center to each corner
+    pub radius: f64,
+    pub sides: usize,
+    // In degrees, with no rotation the first corner points straight up
+    pub rotation: f64,
+}
+
+impl RegularPolygon {
+    pub fn to_polygon(self) -> Polygon {
+        Polygon {
+            corners: (0..self.sides)
+                .map(|index| self.center + direction(self.rotation.to_radians() + 2.0 * PI * index as f64 / self.sides as f64) * self.radius)
+                .collect(),
+        }
+    }
+}
+
+impl Boundary for RegularPolygon {
+    fn contains(&self, point: Coordinates) -> bool {
+        self.to_polygon().contains(point)
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        self.to_polygon().bounding_box()
+    }
+}
+
+// Any simple polygon, the corners can go either way round
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon {
+    pub corners: Vec<Coordinates>,
+}
+
+impl Polygon {
+    // A star with the given number of points, alternating between the outer and inner radius
+    pub fn star(center: Coordinates, outer_radius: f64, inner_radius: f64, points: usize) -> Polygon {
+        Polygon {
+            corners: (0..points * 2)
+                .map(|index| {
+                    let radius = if index % 2 == 0 { outer_radius } else { inner_radius };
+                    center + direction(PI * index as f64 / points as f64) * radius
+                })
+                .collect(),
+        }
+    }
+
+    // A heart that reaches out about the given size from its center in every direction
+    pub fn heart(center: Coordinates, size: f64) -> Polygon {
+        const CORNERS: usize = 64;
+        // The classic heart curve spans 32 units across and 29 down, and is shifted to sit evenly around the center
+        let unit = size / 16.0;
+        Polygon {
+            corners: (0..CORNERS)
+                .map(|index| {
+                    let t = 2.0 * PI * index as f64 / CORNERS as f64;
+                    let x = 16.0 * t.sin().powi(3);
+                    let y = 13.0 * t.cos() - 5.0 * (2.0 * t).cos() - 2.0 * (3.0 * t).cos() - (4.0 * t).cos();
+                    center + Coordinates { x, y: -2.5 - y } * unit
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Boundary for Polygon {
+    fn contains(&self, point: Coordinates) -> bool {
+        let mut inside = false;
+        for i in 0..self.corners.len() {
+            let a = self.corners[i];
+            let b = self.corners[(i + 1) % self.corners.len()];
+            if (a.y > point.y) != (b.y > point.y) && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    fn bounding_box(&self) -> Rectangle {
+        let min_x = self.corners.iter().map(|corner| corner.x).fold(f64::MAX, f64::min);
+        let min_y = self.corners.iter().map(|corner| corner.y).fold(f64::MAX, f64::min);
+        let max_x = self.corners.iter().map(|corner| corner.x).fold(f64::MIN, f64::max);
+        let max_y = self.corners.iter().map(|corner| corner.y).fold(f64::MIN, f64::max);
+        Rectangle {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        }
+    }
+}
+
+// Unit vector at the given angle clockwise from straight up, as the y axis points down the screen
+fn direction(angle: f64) -> Coordinates {
+    Coordinates { x: angle.sin(), y: -angle.cos() }
+}
@@ -1,18 +1,53 @@
-use std::{collections::HashMap};
+use std::{collections::{HashMap, HashSet}, hash::Hash};
 
 use crate::maze::{Maze, Offset};
-use rand::prelude::ThreadRng;
+use rand::prelude::StdRng;
 
+pub mod aldous_broder;
+pub mod binary_tree;
+pub mod eller;
+pub mod growing_tree;
+pub mod hunt_and_kill;
+pub mod kruskal;
+pub mod prim;
+pub mod recursive_backtracker;
 pub mod recursive_division;
+pub mod sidewinder;
+pub mod weave;
+pub mod wilson;
 
+// Post processors are generators too, they just start from a finished maze rather than a blank one.
+// A maze can have no cells at all, in which case step should report that it has finished straight away.
 pub trait Generator {
-    fn init(maze: &Maze, rand: ThreadRng, options: HashMap<&'static str, usize>) -> Self
+    fn init(maze: &Maze, rand: StdRng, options: HashMap<&'static str, usize>) -> Self
         where Self: Sized;
     fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate>;
     fn options(maze: &Maze) -> Vec<GeneratorOption>
         where Self: Sized;
 }
 
+type GeneratorInit = fn(&Maze, StdRng, HashMap<&'static str, usize>) -> Box<dyn Generator>;
+
+// Lets a generator be picked at runtime without knowing its type
+#[derive(Clone, Copy)]
+pub struct GeneratorType {
+    pub init: GeneratorInit,
+    pub options: fn(&Maze) -> Vec<GeneratorOption>,
+}
+
+impl GeneratorType {
+    pub fn of<G: Generator + 'static>() -> GeneratorType {
+        GeneratorType {
+            init: init_boxed::<G>,
+            options: G::options,
+        }
+    }
+}
+
+fn init_boxed<G: Generator + 'static>(maze: &Maze, rng: StdRng, options: HashMap<&'static str, usize>) -> Box<dyn Generator> {
+    Box::new(G::init(maze, rng, options))
+}
+
 pub struct GeneratorOption {
     pub min: usize,
     pub default: usize,
@@ -23,8 +58,128 @@ pub struct GeneratorOption {
 pub enum GeneratorUpdate {
     Connect(Offset, Offset),
     Disconnect(Offset, Offset),
+    // A passage from the first cell that goes under the second and comes out in the third
+    Tunnel(Offset, Offset, Offset),
     Primary(Offset),
     Secondary(Offset),
     Normal(Offset),
     Finished()
 }
+
+// Every pair of adjacent cells in the maze, each pair listed once
+pub fn edges(maze: &Maze) -> Vec<(Offset, Offset)> {
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+    for offset in maze.offsets() {
+        seen.insert(offset);
+        for adjacency in maze.adjacencies(offset) {
+            if !seen.contains(&adjacency.offset) {
+                edges.push((offset, adjacency.offset));
+            }
+        }
+    }
+    edges
+}
+
+// Cells grouped into horizontal bands one scaled unit tall, top to bottom, each sorted left to right
+pub fn rows(maze: &Maze) -> Vec<Vec<Offset>> {
+    let mut cells = maze.offsets();
+    cells.sort_by(|a, b| {
+        a.coordinates.y.partial_cmp(&b.coordinates.y).unwrap()
+            .then(a.coordinates.x.partial_cmp(&b.coordinates.x).unwrap())
+    });
+
+    let mut rows: Vec<Vec<Offset>> = Vec::new();
+    if let Some(top) = cells.first().map(|cell| cell.coordinates.y) {
+        for cell in cells {
+            let row = band(maze, top, cell);
+            if rows.len() <= row {
+                rows.resize(row + 1, Vec::new());
+            }
+            rows[row].push(cell);
+        }
+    }
+
+    rows.retain(|row| !row.is_empty());
+    for row in &mut rows {
+        row.sort_by(|a, b| a.coordinates.x.partial_cmp(&b.coordinates.x).unwrap());
+    }
+    rows
+}
+
+fn band(maze: &Maze, top: f64, cell: Offset) -> usize {
+    ((cell.coordinates.y - top) / maze.scaling).round() as usize
+}
+
+// A single one of the bands that rows splits the maze into, counting down from the top cell's height, along with
+// the next band down that has any cells. Sweeping down a band at a time like this never holds more than one row.
+pub fn row(maze: &Maze, top: f64, index: usize) -> (Vec<Offset>, Option<usize>) {
+    let mut row = Vec::new();
+    let mut next: Option<usize> = None;
+    for &cell in maze.cells.keys() {
+        let band = band(maze, top, cell);
+        if band == index {
+            row.push(cell);
+        } else if band > index && next.is_none_or(|next| band < next) {
+            next = Some(band);
+        }
+    }
+
+    // In the same order as rows, which breaks ties between cells at the same place by height and then offset
+    row.sort_by(|a, b| {
+        a.coordinates.x.partial_cmp(&b.coordinates.x).unwrap()
+            .then(a.coordinates.y.partial_cmp(&b.coordinates.y).unwrap())
+            .then(a.cmp(b))
+    });
+    (row, next)
+}
+
+// Union-find over arbitrary keys.  Keys that have never been seen are their own set.
+pub struct DisjointSet<T: Copy + Eq + Hash> {
+    parents: HashMap<T, T>,
+}
+
+impl<T: Copy + Eq + Hash> Default for DisjointSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Eq + Hash> DisjointSet<T> {
+    pub fn new() -> Self {
+        DisjointSet {
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn find(&mut self, item: T) -> T {
+        let mut root = item;
+        while let Some(&parent) = self.parents.get(&root) {
+            if parent == root {
+                break;
+            }
+            root = parent;
+        }
+
+        // Path compression
+        let mut current = item;
+        while current != root {
+            let next = self.parents[&current];
+            self.parents.insert(current, root);
+            current = next;
+        }
+
+        root
+    }
+
+    // Returns false if the two items were already in the same set
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return false;
+        }
+        self.parents.insert(root_a, root_b);
+        true
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::prelude::{IteratorRandom, StdRng};
+
+use crate::maze::{Maze, Offset};
+
+use super::{Generator, GeneratorOption, GeneratorUpdate, wilson::Wilson};
+
+pub struct AldousBroder {
+    rng: StdRng,
+    visited: HashSet<Offset>,
+    current: Offset,
+    switch_percent: usize,
+    wilson: Option<Wilson>,
+    finished: bool,
+}
+
+impl Generator for AldousBroder {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        if let Some(wilson) = &mut self.wilson {
+            return wilson.step(maze);
+        }
+
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            if self.visited.len() == maze.cells.len() {
+                self.finished = true;
+                changes.push(GeneratorUpdate::Normal(self.current));
+            } else if self.visited.len() * 100 >= self.switch_percent * maze.cells.len() {
+                // Hybrid mode: Wilson's algorithm finishes the remaining cells much faster than the random walk
+                changes.push(GeneratorUpdate::Normal(self.current));
+                let mut wilson = Wilson::from_tree(maze, self.rng.clone(), self.visited.clone());
+                changes.extend(wilson.step(maze));
+                self.wilson = Some(wilson);
+                return changes;
+            } else {
+                let next = maze.adjacencies(self.current)
+                    .map(|adj| adj.offset)
+                    .choose(&mut self.rng)
+                    .unwrap();
+
+                if self.visited.insert(next) {
+                    changes.push(GeneratorUpdate::Connect(self.current, next));
+                }
+                changes.push(GeneratorUpdate::Normal(self.current));
+                changes.push(GeneratorUpdate::Primary(next));
+                self.current = next;
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, mut rng: StdRng, options: HashMap<&'static str, usize>) -> Self {
+        let start = maze.offsets().into_iter().choose(&mut rng);
+        let visited: HashSet<Offset> = start.into_iter().collect();
+
+        AldousBroder {
+            rng,
+            visited,
+            current: start.unwrap_or(Offset::origin()),
+            switch_percent: options["Wilson Switch %"],
+            wilson: None,
+            finished: start.is_none(),
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![
+            GeneratorOption {
+                min: 0,
+                default: 100,
+                max: 100,
+                name: "Wilson Switch %",
+            },
+        ]
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use rand::prelude::{IteratorRandom, StdRng};
+
+use crate::maze::{Maze, Offset};
+
+use super::{DisjointSet, Generator, GeneratorOption, GeneratorUpdate, kruskal::Kruskal, rows};
+
+// The neighbors that lie most nearly straight up and straight right on screen, out of those up and to the right.
+// Carving only in these directions can never make a loop, whatever the tiling. Stairs lead straight up or down a
+// layer rather than anywhere on screen, so they are never picked.
+pub fn up_and_right(maze: &Maze, cell: Offset) -> (Option<Offset>, Option<Offset>) {
+    let mut up: Option<(f64, Offset)> = None;
+    let mut right: Option<(f64, Offset)> = None;
+    let sides = maze.get_polygon(&maze.cells[&cell]).sides.len();
+
+    for adjacency in maze.adjacencies(cell).filter(|adjacency| adjacency.index < sides) {
+        let direction = adjacency.offset.coordinates - cell.coordinates;
+        let length = (direction.x * direction.x + direction.y * direction.y).sqrt();
+        if (direction.x - direction.y) / length < 1e-6 {
+            continue;
+        }
+
+        let upness = -direction.y / length;
+        if up.is_none_or(|(best, _)| upness > best) {
+            up = Some((upness, adjacency.offset));
+        }
+        let rightness = direction.x / length;
+        if right.is_none_or(|(best, _)| rightness > best) {
+            right = Some((rightness, adjacency.offset));
+        }
+    }
+
+    (up.map(|(_, offset)| offset), right.map(|(_, offset)| offset))
+}
+
+pub struct BinaryTree {
+    rng: StdRng,
+    order: Vec<Offset>,
+    sets: DisjointSet<Offset>,
+    current: Option<Offset>,
+    // Cells stranded by the edges of the maze are joined up afterwards. Each layer is carved on its own, so this
+    // is also where the layers get joined, by a staircase between each pair.
+    remainder: Option<Kruskal>,
+}
+
+impl Generator for BinaryTree {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        if let Some(remainder) = &mut self.remainder {
+            return remainder.step(maze);
+        }
+
+        let mut changes = Vec::new();
+
+        if let Some(current) = self.current.take() {
+            changes.push(GeneratorUpdate::Normal(current));
+        }
+
+        if let Some(cell) = self.order.pop() {
+            let (up, right) = up_and_right(maze, cell);
+            if let Some(next) = up.into_iter().chain(right).choose(&mut self.rng) {
+                changes.push(GeneratorUpdate::Connect(cell, next));
+                self.sets.union(cell, next);
+            }
+            changes.push(GeneratorUpdate::Primary(cell));
+            self.current = Some(cell);
+        } else {
+            let sets = std::mem::take(&mut self.sets);
+            let mut remainder = Kruskal::with_sets(maze, self.rng.clone(), sets);
+            changes.extend(remainder.step(maze));
+            self.remainder = Some(remainder);
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        let mut order: Vec<Offset> = rows(maze).into_iter().flatten().collect();
+        order.reverse();
+
+        BinaryTree {
+            rng,
+            order,
+            sets: DisjointSet::new(),
+            current: None,
+            remainder: None,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::prelude::{SliceRandom, StdRng};
+use rand::Rng;
+
+use crate::maze::{Maze, Offset};
+
+use super::{DisjointSet, Generator, GeneratorOption, GeneratorUpdate, kruskal::Kruskal, row};
+
+pub struct Eller {
+    rng: StdRng,
+    // Height of the top cell, which the bands are counted down from
+    top: f64,
+    // The band to sweep next, or none once the bottom has been passed
+    band: Option<usize>,
+    // The band swept last, to clear its highlight
+    previous: Vec<Offset>,
+    // Passages carved so far, a maze with every cell joined up has one fewer than it has cells
+    passages: usize,
+    // Set ids for the current row and the cells below it that have already been carved into
+    sets: HashMap<Offset, usize>,
+    next_set: usize,
+    // Sets that have not been connected to any earlier row yet
+    fresh: HashSet<usize>,
+    // Cells stranded by the edges of the maze are joined up afterwards
+    remainder: Option<Kruskal>,
+}
+
+impl Eller {
+    fn set_of(&mut self, cell: Offset) -> usize {
+        if let Some(&set) = self.sets.get(&cell) {
+            return set;
+        }
+        let set = self.next_set;
+        self.next_set += 1;
+        self.sets.insert(cell, set);
+        self.fresh.insert(set);
+        set
+    }
+
+    fn merge(&mut self, from: usize, into: usize) {
+        for set in self.sets.values_mut() {
+            if *set == from {
+                *set = into;
+            }
+        }
+        if !self.fresh.remove(&from) {
+            self.fresh.remove(&into);
+        }
+    }
+
+    // Connects the two cells if that joins two different sets
+    fn join(&mut self, a: Offset, b: Offset, changes: &mut Vec<GeneratorUpdate>) -> bool {
+        let set_a = self.set_of(a);
+        let set_b = self.set_of(b);
+        if set_a == set_b {
+            return false;
+        }
+        self.merge(set_a, set_b);
+        changes.push(GeneratorUpdate::Connect(a, b));
+        self.passages += 1;
+        true
+    }
+
+    fn sweep_row(&mut self, maze: &Maze, row: &[Offset], last: bool, changes: &mut Vec<GeneratorUpdate>) {
+        let in_row: HashSet<Offset> = row.iter().copied().collect();
+        let bottom = row.iter().map(|cell| cell.coordinates.y).fold(f64::MIN, f64::max);
+
+        for &cell in row {
+            self.set_of(cell);
+        }
+
+        // Merge sets along the row, always merging everything on the final row
+        let mut across = Vec::new();
+        let mut below = Vec::new();
+        for &cell in row {
+            for adjacency in maze.adjacencies(cell) {
+                let other = adjacency.offset;
+                if in_row.contains(&other) {
+                    if other.coordinates.x > cell.coordinates.x {
+                        across.push((cell, other));
+                    }
+                } else if other.coordinates.y > bottom {
+                    below.push((cell, other));
+                }
+            }
+        }
+
+        for (cell, other) in across.iter().copied() {
+            if last || self.rng.gen_bool(0.5) {
+                self.join(cell, other, changes);
+            }
+        }
+
+        if last {
+            return;
+        }
+
+        // Every set carves down at least once so that it carries on into the rows below
+        below.shuffle(&mut self.rng);
+        let mut carved = HashSet::new();
+        for (cell, other) in below.iter().copied() {
+            let set = self.sets[&cell];
+            if (!carved.contains(&set) || self.rng.gen_bool(0.3)) && self.join(cell, other, changes) {
+                carved.insert(self.sets[&cell]);
+            }
+        }
+
+        // A set that cannot go down has to join a neighbor in its row instead
+        for (cell, other) in across {
+            let set = self.sets[&cell];
+            if !carved.contains(&set) && self.join(cell, other, changes) {
+                carved.insert(self.sets[&cell]);
+            }
+        }
+
+        // Failing that, a set that is not attached to anything yet can safely connect back up
+        for &cell in row {
+            let set = self.sets[&cell];
+            if carved.contains(&set) || !self.fresh.contains(&set) {
+                continue;
+            }
+            let above = maze.adjacencies(cell)
+                .map(|adj| adj.offset)
+                .find(|other| !in_row.contains(other) && other.coordinates.y < cell.coordinates.y);
+            if let Some(above) = above {
+                changes.push(GeneratorUpdate::Connect(cell, above));
+                self.passages += 1;
+                self.fresh.remove(&set);
+                carved.insert(set);
+            }
+        }
+
+        // Everything still being tracked is now attached to a finished row
+        for cell in row {
+            self.sets.remove(cell);
+        }
+        self.fresh.clear();
+    }
+}
+
+impl Generator for Eller {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        if let Some(remainder) = &mut self.remainder {
+            return remainder.step(maze);
+        }
+
+        let mut changes = Vec::new();
+
+        for cell in self.previous.drain(..) {
+            changes.push(GeneratorUpdate::Normal(cell));
+        }
+
+        if let Some(band) = self.band {
+            let (row, next) = row(maze, self.top, band);
+            for &cell in &row {
+                changes.push(GeneratorUpdate::Secondary(cell));
+            }
+            self.sweep_row(maze, &row, next.is_none(), &mut changes);
+            self.previous = row;
+            self.band = next;
+        } else if self.passages + 1 >= maze.cells.len() {
+            changes.push(GeneratorUpdate::Finished());
+        } else {
+            // Some sets were closed off by the edges of the maze. The row-by-row sets are gone by now, so rebuild them
+            // from the passages that were carved
+            let mut sets = DisjointSet::new();
+            for &offset in maze.cells.keys() {
+                for other in maze.passages(offset) {
+                    sets.union(offset, other);
+                }
+            }
+            let mut remainder = Kruskal::with_sets(maze, self.rng.clone(), sets);
+            changes.extend(remainder.step(maze));
+            self.remainder = Some(remainder);
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        Eller {
+            rng,
+            top: maze.cells.keys().map(|cell| cell.coordinates.y).fold(f64::MAX, f64::min),
+            band: (!maze.cells.is_empty()).then_some(0),
+            previous: Vec::new(),
+            passages: 0,
+            sets: HashMap::new(),
+            next_set: 0,
+            fresh: HashSet::new(),
+            remainder: None,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
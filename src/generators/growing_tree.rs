@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::prelude::{IteratorRandom, StdRng};
+use rand::Rng;
+
+use crate::maze::{Maze, Offset};
+
+use super::{Generator, GeneratorOption, GeneratorUpdate};
+
+pub struct GrowingTree {
+    rng: StdRng,
+    visited: HashSet<Offset>,
+    active: Vec<Offset>,
+    current: Option<Offset>,
+    // Weights for picking the newest, oldest, a random and the middle active cell
+    weights: [usize; 4],
+    finished: bool,
+}
+
+impl GrowingTree {
+    fn select(&mut self) -> usize {
+        let last = self.active.len() - 1;
+        let total: usize = self.weights.iter().sum();
+        if total == 0 {
+            return last;
+        }
+
+        let mut roll = self.rng.gen_range(0..total);
+        let strategy = self.weights.iter().position(|&weight| {
+            if roll < weight {
+                true
+            } else {
+                roll -= weight;
+                false
+            }
+        });
+
+        match strategy {
+            Some(1) => 0,
+            Some(2) => self.rng.gen_range(0..=last),
+            Some(3) => last / 2,
+            _ => last,
+        }
+    }
+}
+
+impl Generator for GrowingTree {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            if let Some(current) = self.current.take() {
+                changes.push(GeneratorUpdate::Secondary(current));
+            }
+
+            if self.active.is_empty() {
+                self.finished = true;
+            } else {
+                let index = self.select();
+                let cell = self.active[index];
+
+                let visited = &self.visited;
+                let next = maze.adjacencies(cell)
+                    .map(|adj| adj.offset)
+                    .filter(|offset| !visited.contains(offset))
+                    .choose(&mut self.rng);
+
+                if let Some(next) = next {
+                    changes.push(GeneratorUpdate::Connect(cell, next));
+                    changes.push(GeneratorUpdate::Primary(next));
+                    self.visited.insert(next);
+                    self.active.push(next);
+                    self.current = Some(next);
+                } else {
+                    // Order matters for the oldest and middle strategies, so no swap_remove
+                    self.active.remove(index);
+                    changes.push(GeneratorUpdate::Normal(cell));
+                }
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, mut rng: StdRng, options: HashMap<&'static str, usize>) -> Self {
+        let start = maze.offsets().into_iter().choose(&mut rng);
+        let visited: HashSet<Offset> = start.into_iter().collect();
+
+        GrowingTree {
+            rng,
+            visited,
+            active: start.into_iter().collect(),
+            current: start,
+            weights: [
+                options["Newest %"],
+                options["Oldest %"],
+                options["Random %"],
+                options["Middle %"],
+            ],
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![
+            GeneratorOption {
+                min: 0,
+                default: 100,
+                max: 100,
+                name: "Newest %",
+            },
+            GeneratorOption {
+                min: 0,
+                default: 0,
+                max: 100,
+                name: "Oldest %",
+            },
+            GeneratorOption {
+                min: 0,
+                default: 0,
+                max: 100,
+                name: "Random %",
+            },
+            GeneratorOption {
+                min: 0,
+                default: 0,
+                max: 100,
+                name: "Middle %",
+            },
+        ]
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::prelude::{IteratorRandom, StdRng};
+
+use crate::maze::{Maze, Offset};
+
+use super::{Generator, GeneratorOption, GeneratorUpdate, rows};
+
+pub struct HuntAndKill {
+    rng: StdRng,
+    visited: HashSet<Offset>,
+    current: Option<Offset>,
+    rows: Vec<Vec<Offset>>,
+    hunt_row: usize,
+    scanned: Vec<Offset>,
+    finished: bool,
+}
+
+impl HuntAndKill {
+    // Scans a single row for an unvisited cell next to the carved region, returning it with the cell to connect it to
+    fn hunt(&mut self, maze: &Maze, row: usize) -> Option<(Offset, Offset)> {
+        let visited = &self.visited;
+        for &cell in &self.rows[row] {
+            if visited.contains(&cell) {
+                continue;
+            }
+            let neighbor = maze.adjacencies(cell)
+                .map(|adj| adj.offset)
+                .filter(|offset| visited.contains(offset))
+                .choose(&mut self.rng);
+
+            if let Some(neighbor) = neighbor {
+                return Some((cell, neighbor));
+            }
+        }
+        None
+    }
+}
+
+impl Generator for HuntAndKill {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            for cell in self.scanned.drain(..) {
+                changes.push(GeneratorUpdate::Normal(cell));
+            }
+
+            if let Some(current) = self.current {
+                // Kill: random walk until there is nowhere left to go
+                let visited = &self.visited;
+                let next = maze.adjacencies(current)
+                    .map(|adj| adj.offset)
+                    .filter(|offset| !visited.contains(offset))
+                    .choose(&mut self.rng);
+
+                changes.push(GeneratorUpdate::Normal(current));
+                if let Some(next) = next {
+                    changes.push(GeneratorUpdate::Connect(current, next));
+                    changes.push(GeneratorUpdate::Primary(next));
+                    self.visited.insert(next);
+                    self.current = Some(next);
+                } else {
+                    self.current = None;
+                    self.hunt_row = 0;
+                }
+            } else {
+                // Hunt: scan one row per step, skipping rows that are already fully carved
+                while self.hunt_row < self.rows.len() && self.rows[self.hunt_row].iter().all(|cell| self.visited.contains(cell)) {
+                    self.hunt_row += 1;
+                }
+
+                if self.hunt_row < self.rows.len() {
+                    let found = self.hunt(maze, self.hunt_row);
+
+                    for &cell in &self.rows[self.hunt_row] {
+                        changes.push(GeneratorUpdate::Secondary(cell));
+                        self.scanned.push(cell);
+                    }
+
+                    if let Some((cell, neighbor)) = found {
+                        changes.push(GeneratorUpdate::Connect(neighbor, cell));
+                        changes.push(GeneratorUpdate::Primary(cell));
+                        self.visited.insert(cell);
+                        self.current = Some(cell);
+                    } else {
+                        self.hunt_row += 1;
+                    }
+                } else {
+                    self.finished = true;
+                }
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, mut rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        let current = maze.offsets().into_iter().choose(&mut rng);
+        let visited: HashSet<Offset> = current.into_iter().collect();
+
+        HuntAndKill {
+            rng,
+            visited,
+            current,
+            rows: rows(maze),
+            hunt_row: 0,
+            scanned: Vec::new(),
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use rand::prelude::{SliceRandom, StdRng};
+
+use crate::maze::{Maze, Offset};
+
+use super::{DisjointSet, Generator, GeneratorOption, GeneratorUpdate, edges};
+
+pub struct Kruskal {
+    edges: Vec<(Offset, Offset)>,
+    sets: DisjointSet<Offset>,
+    highlighted: Vec<Offset>,
+    finished: bool,
+}
+
+impl Kruskal {
+    // Continues from cells that have already been joined, connecting whatever sets remain
+    pub fn with_sets(maze: &Maze, mut rng: StdRng, sets: DisjointSet<Offset>) -> Self {
+        let mut edges = edges(maze);
+        edges.shuffle(&mut rng);
+
+        Kruskal {
+            edges,
+            sets,
+            highlighted: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl Generator for Kruskal {
+    fn step(&mut self, _maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            for cell in self.highlighted.drain(..) {
+                changes.push(GeneratorUpdate::Normal(cell));
+            }
+
+            // Skip over edges that would create a loop so every step carves a passage
+            while let Some((a, b)) = self.edges.pop() {
+                if self.sets.union(a, b) {
+                    changes.push(GeneratorUpdate::Connect(a, b));
+                    changes.push(GeneratorUpdate::Primary(a));
+                    changes.push(GeneratorUpdate::Primary(b));
+                    self.highlighted.push(a);
+                    self.highlighted.push(b);
+                    break;
+                }
+            }
+
+            if self.edges.is_empty() {
+                self.finished = true;
+                for cell in self.highlighted.drain(..) {
+                    changes.push(GeneratorUpdate::Normal(cell));
+                }
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        Kruskal::with_sets(maze, rng, DisjointSet::new())
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
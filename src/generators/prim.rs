@@ -0,0 +1,92 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::prelude::{IteratorRandom, StdRng};
+use rand::Rng;
+
+use crate::maze::{Maze, Offset};
+
+use super::{Generator, GeneratorOption, GeneratorUpdate};
+
+pub struct Prim {
+    rng: StdRng,
+    carved: HashSet<Offset>,
+    frontier: Vec<Offset>,
+    in_frontier: HashSet<Offset>,
+    start: Option<Offset>,
+    current: Option<Offset>,
+    finished: bool,
+}
+
+impl Prim {
+    fn expand(&mut self, maze: &Maze, cell: Offset, changes: &mut Vec<GeneratorUpdate>) {
+        for adjacency in maze.adjacencies(cell) {
+            let neighbor = adjacency.offset;
+            if !self.carved.contains(&neighbor) && self.in_frontier.insert(neighbor) {
+                self.frontier.push(neighbor);
+                changes.push(GeneratorUpdate::Secondary(neighbor));
+            }
+        }
+    }
+}
+
+impl Generator for Prim {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            if let Some(previous) = self.current.take() {
+                changes.push(GeneratorUpdate::Normal(previous));
+            }
+
+            if let Some(start) = self.start.take() {
+                changes.push(GeneratorUpdate::Primary(start));
+                self.carved.insert(start);
+                self.current = Some(start);
+                self.expand(maze, start, &mut changes);
+            } else if self.frontier.is_empty() {
+                self.finished = true;
+            } else {
+                let index = self.rng.gen_range(0..self.frontier.len());
+                let next = self.frontier.swap_remove(index);
+                self.in_frontier.remove(&next);
+
+                let carved = &self.carved;
+                let carved_neighbor = maze.adjacencies(next)
+                    .map(|adj| adj.offset)
+                    .filter(|offset| carved.contains(offset))
+                    .choose(&mut self.rng)
+                    .unwrap();
+
+                changes.push(GeneratorUpdate::Connect(carved_neighbor, next));
+                changes.push(GeneratorUpdate::Primary(next));
+                self.carved.insert(next);
+                self.current = Some(next);
+                self.expand(maze, next, &mut changes);
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, mut rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        let start = maze.offsets().into_iter().choose(&mut rng);
+
+        Prim {
+            rng,
+            carved: HashSet::new(),
+            frontier: Vec::new(),
+            in_frontier: HashSet::new(),
+            start,
+            current: None,
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
@@ -1,139 +1,185 @@
-use std::{cmp::{max, min}, collections::{HashMap, HashSet}};
+use std::collections::{HashMap, HashSet};
 
-use rand::prelude::{IteratorRandom, ThreadRng};
+use rand::prelude::{SliceRandom, StdRng};
+use rand::Rng;
 
-use crate::maze::{Maze, Offset};
+use crate::maze::{Coordinates, Maze, Offset, rotate};
 
-use super::{Generator, GeneratorOption, GeneratorUpdate};
+use super::{DisjointSet, Generator, GeneratorOption, GeneratorUpdate, edges};
 
-pub struct RecursiveDivisionNode {
-    stack: Vec<Offset>,
-    cells: HashSet<Offset>,
-    id: usize,
-    current: Offset,
+pub struct RecursiveDivision {
+    rng: StdRng,
+    regions: Vec<Vec<Offset>>,
+    opened: bool,
+    highlighted: Vec<Offset>,
     finished: bool,
 }
 
-pub struct RecursiveDivision {
-    rng: ThreadRng,
-    nodes: Vec<RecursiveDivisionNode>,
-    finished: bool,
+// Splits the cells into groups that are connected through cells in the same group
+fn components(maze: &Maze, cells: &[Offset]) -> Vec<Vec<Offset>> {
+    let mut remaining: HashSet<Offset> = cells.iter().copied().collect();
+    let mut components = Vec::new();
+
+    for &cell in cells {
+        if !remaining.remove(&cell) {
+            continue;
+        }
+        let mut component = vec![cell];
+        let mut stack = vec![cell];
+        while let Some(current) = stack.pop() {
+            for adjacency in maze.adjacencies(current) {
+                if remaining.remove(&adjacency.offset) {
+                    component.push(adjacency.offset);
+                    stack.push(adjacency.offset);
+                }
+            }
+        }
+        components.push(component);
+    }
+
+    components
 }
 
-impl Generator for RecursiveDivision {
-    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
-        let mut changes = Vec::new();
+impl RecursiveDivision {
+    // Picks a line through the cell centroids that divides the region in two, returning the cells on one side of it
+    fn split_line(&mut self, maze: &Maze, region: &[Offset]) -> Option<HashSet<Offset>> {
+        // Layers are split apart first, otherwise the cells stacked on top of each other could never be divided
+        let mut layers: Vec<usize> = region.iter().map(|cell| cell.layer).collect();
+        layers.sort_unstable();
+        layers.dedup();
+        if layers.len() > 1 {
+            let threshold = layers[self.rng.gen_range(0..layers.len() - 1)];
+            return Some(region.iter().filter(|cell| cell.layer <= threshold).copied().collect());
+        }
 
-        if !self.finished {
+        // Follow the maze rotation so that square tilings get straight walls
+        let axes = [
+            rotate(Coordinates { x: 1.0, y: 0.0 }, Coordinates::origin(), maze.rotation),
+            rotate(Coordinates { x: 0.0, y: 1.0 }, Coordinates::origin(), maze.rotation),
+        ];
+
+        let mut projections: Vec<Vec<f64>> = axes.iter()
+            .map(|axis| region.iter().map(|cell| cell.coordinates.x * axis.x + cell.coordinates.y * axis.y).collect())
+            .collect();
+
+        let extent = |values: &Vec<f64>| {
+            values.iter().cloned().fold(f64::MIN, f64::max) - values.iter().cloned().fold(f64::MAX, f64::min)
+        };
+        // Cut across the longer side of the region
+        if extent(&projections[1]) > extent(&projections[0]) {
+            projections.swap(0, 1);
+        }
 
-            for node_idx in 0..self.nodes.len() {
-                
-                let next_neighbor = {
-                    let adjacent_cells: Vec<Offset> = 
-                    maze.adjacencies(self.nodes[node_idx].current)
-                        .map(|adj| adj.offset)
-                        .filter(|offset| {
-                            for other in &self.nodes {
-                                if other.id == self.nodes[node_idx].id && other.cells.contains(offset) {
-                                    return false;
-                                }
-                            }
-                            return true
-                        })
-                        .collect();
-
-                    adjacent_cells.iter().choose(&mut self.rng).copied()
-                };
-
-                let mut updates = None;
-
-                if let Some(next) = next_neighbor {
-                    for node in &self.nodes {
-                        if node.id != node_idx && node.cells.contains(&next) {
-                            let new_id = min(node.id, self.nodes[node_idx].id);
-                            let old_id = max(node.id, self.nodes[node_idx].id);
-                            updates = Some((new_id, old_id));
-                            break;
-                        }
-                    }
+        for values in projections {
+            let mut distinct = values.clone();
+            distinct.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            distinct.dedup_by(|a, b| (*a - *b).abs() < maze.scaling * 0.01);
 
-                    if let Some((new_id, old_id)) = updates {
-                        for node in &mut self.nodes {
-                            if node.id == old_id {
-                                node.id = new_id;
-                            }
-                        }
-                    }
-                }
+            if distinct.len() < 2 {
+                continue;
+            }
+
+            let index = self.rng.gen_range(0..distinct.len() - 1);
+            let threshold = (distinct[index] + distinct[index + 1]) / 2.0;
+
+            return Some(region.iter()
+                .zip(values)
+                .filter(|(_, value)| *value < threshold)
+                .map(|(cell, _)| *cell)
+                .collect());
+        }
+
+        None
+    }
 
-                let node = &mut self.nodes[node_idx];
-
-                if let Some(next) = next_neighbor {
-                    changes.push(GeneratorUpdate::Connect(node.current, next));
-                    changes.push(GeneratorUpdate::Secondary(node.current));
-                    changes.push(GeneratorUpdate::Primary(next));
-                    node.cells.insert(next);
-                    node.stack.push(node.current);
-                    node.current = next;
-                } else {
-                    changes.push(GeneratorUpdate::Normal(node.current));
-
-                    if let Some(backtrack) = node.stack.pop() {
-                        changes.push(GeneratorUpdate::Primary(backtrack));
-                        node.current = backtrack;
-                    } else {
-                        node.finished = true;
+    fn divide(&mut self, maze: &Maze, region: Vec<Offset>, changes: &mut Vec<GeneratorUpdate>) {
+        let side = match self.split_line(maze, &region) {
+            Some(side) => side,
+            None => return,
+        };
+        let (first, second): (Vec<Offset>, Vec<Offset>) = region.iter().partition(|cell| side.contains(cell));
+
+        // A straight cut through an irregular region can leave either side in several pieces
+        let mut pieces = components(maze, &first);
+        pieces.extend(components(maze, &second));
+
+        let mut piece_indexes = HashMap::new();
+        for (index, piece) in pieces.iter().enumerate() {
+            for &cell in piece {
+                piece_indexes.insert(cell, index);
+            }
+        }
+
+        let mut wall = Vec::new();
+        for &cell in &first {
+            for adjacency in maze.adjacencies(cell) {
+                if let Some(&other) = piece_indexes.get(&adjacency.offset) {
+                    if !side.contains(&adjacency.offset) {
+                        wall.push((cell, adjacency.offset, piece_indexes[&cell], other));
                     }
                 }
             }
-            if self.nodes.iter().all(|n| n.finished){
+        }
+        wall.shuffle(&mut self.rng);
+
+        // Leave exactly enough gaps to keep every piece reachable
+        let mut joined = DisjointSet::new();
+        for (cell, other, cell_piece, other_piece) in wall {
+            if joined.union(cell_piece, other_piece) {
+                changes.push(GeneratorUpdate::Primary(cell));
+                changes.push(GeneratorUpdate::Primary(other));
+                self.highlighted.push(cell);
+                self.highlighted.push(other);
+            } else {
+                changes.push(GeneratorUpdate::Disconnect(cell, other));
+            }
+        }
+
+        self.regions.extend(pieces.into_iter().filter(|piece| piece.len() > 1));
+    }
+}
+
+impl Generator for RecursiveDivision {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            for cell in self.highlighted.drain(..) {
+                changes.push(GeneratorUpdate::Normal(cell));
+            }
+
+            if !self.opened {
+                for (a, b) in edges(maze) {
+                    changes.push(GeneratorUpdate::Connect(a, b));
+                }
+                self.opened = true;
+            } else if let Some(region) = self.regions.pop() {
+                self.divide(maze, region, &mut changes);
+            } else {
                 self.finished = true;
-                changes.push(GeneratorUpdate::Finished());
             }
-        } else {
+        }
+
+        if self.finished {
             changes.push(GeneratorUpdate::Finished());
         }
 
         changes
     }
 
-    fn init(maze: &Maze, mut rng: ThreadRng, options: HashMap<&'static str, usize>) -> Self {
-        let mut nodes = Vec::new();
-        let mut selected = HashSet::new();
-        for i in 0..options["Threads"] {
-            let current = loop {
-                let current = *maze.cells.keys().choose(&mut rng).unwrap();
-                if selected.insert(current) {
-                    break current;
-                }
-            };
-
-            let mut cells = HashSet::new();
-            cells.insert(current);
-
-            nodes.push(RecursiveDivisionNode {
-                stack: Vec::new(),
-                cells,
-                id: i,
-                current,
-                finished: false,
-            });
-        }
+    fn init(maze: &Maze, rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        let cells = maze.offsets();
+
         RecursiveDivision {
-            nodes,
             rng,
+            regions: components(maze, &cells),
+            opened: false,
+            highlighted: Vec::new(),
             finished: false,
         }
     }
 
-    fn options(maze: &Maze) -> Vec<GeneratorOption> {
-        vec![
-            GeneratorOption {
-                min: 1,
-                default: 1,
-                max: maze.cells.len() / 4,
-                name: "Threads",
-            },
-        ]
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,108 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::prelude::{IteratorRandom, StdRng};
+use rand::Rng;
+
+use crate::maze::{Maze, Offset};
+
+use super::{DisjointSet, Generator, GeneratorOption, GeneratorUpdate, binary_tree::up_and_right, kruskal::Kruskal, rows};
+
+pub struct Sidewinder {
+    rng: StdRng,
+    order: Vec<Offset>,
+    visited: HashSet<Offset>,
+    sets: DisjointSet<Offset>,
+    run: Vec<Offset>,
+    next: Option<Offset>,
+    // Cells stranded by the edges of the maze are joined up afterwards. Runs never take the stairs, so this is
+    // also where the layers get joined, by a staircase between each pair.
+    remainder: Option<Kruskal>,
+}
+
+impl Sidewinder {
+    fn close_run(&mut self, maze: &Maze, changes: &mut Vec<GeneratorUpdate>) {
+        let candidates: Vec<(Offset, Offset)> = self.run.iter()
+            .filter_map(|&cell| up_and_right(maze, cell).0.map(|up| (cell, up)))
+            .collect();
+
+        if let Some(&(cell, up)) = candidates.iter().choose(&mut self.rng) {
+            if self.sets.union(cell, up) {
+                changes.push(GeneratorUpdate::Connect(cell, up));
+            }
+        }
+
+        for cell in self.run.drain(..) {
+            changes.push(GeneratorUpdate::Normal(cell));
+        }
+    }
+}
+
+impl Generator for Sidewinder {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        if let Some(remainder) = &mut self.remainder {
+            return remainder.step(maze);
+        }
+
+        let mut changes = Vec::new();
+
+        // Runs head right, so keep following the current run before going back to the row order
+        let cell = match self.next.take() {
+            Some(cell) => Some(cell),
+            None => {
+                let visited = &self.visited;
+                while self.order.last().is_some_and(|cell| visited.contains(cell)) {
+                    self.order.pop();
+                }
+                self.order.pop()
+            }
+        };
+
+        if let Some(cell) = cell {
+            self.visited.insert(cell);
+            self.run.push(cell);
+            changes.push(GeneratorUpdate::Secondary(cell));
+
+            let right = up_and_right(maze, cell).1.filter(|right| !self.visited.contains(right));
+            let can_close = self.run.iter().any(|&cell| up_and_right(maze, cell).0.is_some());
+
+            match right {
+                Some(right) if !can_close || self.rng.gen_bool(0.5) => {
+                    // The cell to the right can already be joined up some other way, going on would make a loop
+                    if self.sets.union(cell, right) {
+                        changes.push(GeneratorUpdate::Connect(cell, right));
+                        self.next = Some(right);
+                    } else {
+                        self.close_run(maze, &mut changes);
+                    }
+                }
+                _ => self.close_run(maze, &mut changes),
+            }
+        } else {
+            let sets = std::mem::take(&mut self.sets);
+            let mut remainder = Kruskal::with_sets(maze, self.rng.clone(), sets);
+            changes.extend(remainder.step(maze));
+            self.remainder = Some(remainder);
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        let mut order: Vec<Offset> = rows(maze).into_iter().flatten().collect();
+        order.reverse();
+
+        Sidewinder {
+            rng,
+            order,
+            visited: HashSet::new(),
+            sets: DisjointSet::new(),
+            run: Vec::new(),
+            next: None,
+            remainder: None,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
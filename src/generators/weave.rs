@@ -0,0 +1,123 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::{Rng, prelude::{IteratorRandom, SliceRandom, StdRng}};
+
+use crate::maze::{Maze, Offset};
+
+use super::{Generator, GeneratorOption, GeneratorUpdate};
+
+// A recursive backtracker that can also tunnel under straight corridors it has already carved
+pub struct Weave {
+    rng: StdRng,
+    stack: Vec<Offset>,
+    visited: HashSet<Offset>,
+    current: Offset,
+    weave: usize,
+    finished: bool,
+}
+
+impl Weave {
+    // Neighbors that have a corridor going straight across, with an unvisited cell beyond them
+    fn tunnels(&self, maze: &Maze) -> Vec<(Offset, Offset)> {
+        maze.adjacencies(self.current)
+            .filter_map(|adjacency| {
+                let under = &maze.cells[&adjacency.offset];
+                if !self.visited.contains(&under.offset) || under.crossing.is_some() {
+                    return None;
+                }
+
+                let entry = maze.adjacencies(under.offset).find(|adj| adj.offset == self.current)?.index;
+                let exit = maze.opposite_side(under.offset, entry)?;
+                let open: Vec<usize> = (0..under.walls.len()).filter(|&side| !under.walls[side]).collect();
+                let straight = open.len() == 2 && maze.opposite_side(under.offset, open[0]) == Some(open[1]);
+                if !straight || open.contains(&entry) {
+                    return None;
+                }
+
+                let beyond = maze.adjacencies(under.offset).find(|adj| adj.index == exit)?.offset;
+                // On a narrow wrapped maze the cell beyond can also be a direct neighbor, which would leave two walls between them
+                if self.visited.contains(&beyond) || maze.adjacencies(self.current).any(|adj| adj.offset == beyond) {
+                    return None;
+                }
+
+                Some((under.offset, beyond))
+            })
+            .collect()
+    }
+
+    fn move_to(&mut self, next: Offset, changes: &mut Vec<GeneratorUpdate>) {
+        changes.push(GeneratorUpdate::Secondary(self.current));
+        changes.push(GeneratorUpdate::Primary(next));
+        self.visited.insert(next);
+        self.stack.push(self.current);
+        self.current = next;
+    }
+}
+
+impl Generator for Weave {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            let neighbors: Vec<Offset> = maze.adjacencies(self.current)
+                .map(|adjacency| adjacency.offset)
+                .filter(|offset| !self.visited.contains(offset))
+                .collect();
+            let tunnels = self.tunnels(maze);
+
+            let tunnel = if !tunnels.is_empty() && (neighbors.is_empty() || self.rng.gen_range(0..100) < self.weave) {
+                tunnels.choose(&mut self.rng).copied()
+            } else {
+                None
+            };
+
+            if let Some((under, beyond)) = tunnel {
+                changes.push(GeneratorUpdate::Tunnel(self.current, under, beyond));
+                self.move_to(beyond, &mut changes);
+            } else if let Some(&next) = neighbors.choose(&mut self.rng) {
+                changes.push(GeneratorUpdate::Connect(self.current, next));
+                self.move_to(next, &mut changes);
+            } else {
+                changes.push(GeneratorUpdate::Normal(self.current));
+
+                if let Some(backtrack) = self.stack.pop() {
+                    changes.push(GeneratorUpdate::Primary(backtrack));
+                    self.current = backtrack;
+                } else {
+                    self.finished = true;
+                }
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, mut rng: StdRng, options: HashMap<&'static str, usize>) -> Self {
+        let start = maze.offsets().into_iter().choose(&mut rng);
+        let visited: HashSet<Offset> = start.into_iter().collect();
+
+        Weave {
+            rng,
+            stack: Vec::new(),
+            visited,
+            current: start.unwrap_or(Offset::origin()),
+            weave: options["Weave %"],
+            finished: start.is_none(),
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![
+            GeneratorOption {
+                min: 0,
+                default: 50,
+                max: 100,
+                name: "Weave %",
+            },
+        ]
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::prelude::{IteratorRandom, SliceRandom, StdRng};
+
+use crate::maze::{Maze, Offset};
+
+use super::{Generator, GeneratorOption, GeneratorUpdate};
+
+pub struct Wilson {
+    rng: StdRng,
+    tree: HashSet<Offset>,
+    remaining: Vec<Offset>,
+    path: Vec<Offset>,
+    path_indexes: HashMap<Offset, usize>,
+    finished: bool,
+}
+
+impl Wilson {
+    // Starts Wilson's algorithm with the cells in `tree` already carved
+    pub fn from_tree(maze: &Maze, mut rng: StdRng, tree: HashSet<Offset>) -> Self {
+        let mut remaining: Vec<Offset> = maze.offsets().into_iter()
+            .filter(|offset| !tree.contains(offset))
+            .collect();
+        remaining.shuffle(&mut rng);
+
+        Wilson {
+            rng,
+            tree,
+            remaining,
+            path: Vec::new(),
+            path_indexes: HashMap::new(),
+            finished: false,
+        }
+    }
+
+    fn push_path(&mut self, cell: Offset, changes: &mut Vec<GeneratorUpdate>) {
+        self.path_indexes.insert(cell, self.path.len());
+        self.path.push(cell);
+        changes.push(GeneratorUpdate::Primary(cell));
+    }
+}
+
+impl Generator for Wilson {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            if let Some(&last) = self.path.last() {
+                let next = maze.adjacencies(last)
+                    .map(|adj| adj.offset)
+                    .choose(&mut self.rng)
+                    .unwrap();
+
+                if self.tree.contains(&next) {
+                    // The walk reached the tree, so commit the loop-erased path
+                    let mut previous = next;
+                    for cell in self.path.drain(..).rev() {
+                        changes.push(GeneratorUpdate::Connect(previous, cell));
+                        changes.push(GeneratorUpdate::Normal(cell));
+                        self.tree.insert(cell);
+                        previous = cell;
+                    }
+                    self.path_indexes.clear();
+                } else if let Some(&index) = self.path_indexes.get(&next) {
+                    // The walk crossed itself, so erase the loop
+                    for cell in self.path.drain(index + 1..) {
+                        changes.push(GeneratorUpdate::Normal(cell));
+                        self.path_indexes.remove(&cell);
+                    }
+                } else {
+                    self.push_path(next, &mut changes);
+                }
+            } else {
+                while let Some(start) = self.remaining.pop() {
+                    if !self.tree.contains(&start) {
+                        self.push_path(start, &mut changes);
+                        break;
+                    }
+                }
+
+                if self.path.is_empty() {
+                    self.finished = true;
+                }
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, mut rng: StdRng, _options: HashMap<&'static str, usize>) -> Self {
+        let tree = maze.offsets().into_iter().choose(&mut rng).into_iter().collect();
+
+        Wilson::from_tree(maze, rng, tree)
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
@@ -1,9 +1,14 @@
 extern crate wee_alloc;
 
 mod maze;
+mod boundaries;
+mod bitmap;
 mod start;
 mod tilings;
 mod generators;
+mod solvers;
+mod post_processors;
+mod metrics;
 
 
 #[global_allocator]
@@ -1,4 +1,6 @@
-use std::{collections::{HashMap, HashSet}, ops::{Add, Div, Mul, Sub}};
+use std::{collections::{HashMap, HashSet, VecDeque}, ops::{Add, Div, Mul, Sub}};
+
+use crate::{boundaries::Boundary, generators::DisjointSet};
 
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -101,6 +103,8 @@ impl Tiling {
 pub struct Offset {
     pub x: isize,
     pub y: isize,
+    // Which of the stacked copies of the tiling the cell is on, they all share the same coordinates
+    pub layer: usize,
     pub coordinates: Coordinates
 }
 
@@ -111,6 +115,7 @@ impl Add for Offset {
         Offset {
             x: self.x + other.x,
             y: self.y + other.y,
+            layer: self.layer + other.layer,
             coordinates: self.coordinates + other.coordinates,
         }
     }
@@ -118,7 +123,7 @@ impl Add for Offset {
 
 impl PartialEq for Offset {
     fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y
+        self.x == other.x && self.y == other.y && self.layer == other.layer
     }
 }
 
@@ -126,6 +131,7 @@ impl std::hash::Hash for Offset {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.x.hash(state);
         self.y.hash(state);
+        self.layer.hash(state);
     }
 }
 
@@ -134,6 +140,7 @@ impl Offset {
         Offset {
             x: 0,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: 0.0 },
         }
     }
@@ -141,6 +148,19 @@ impl Offset {
 
 impl Eq for Offset {}
 
+// Layer by layer and then row by row, so sorting cells gives a stable order that does not depend on hashing
+impl Ord for Offset {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.layer, self.y, self.x).cmp(&(other.layer, other.y, other.x))
+    }
+}
+
+impl PartialOrd for Offset {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Sub for Offset {
     type Output = Offset;
 
@@ -148,6 +168,7 @@ impl Sub for Offset {
         Offset {
             x: self.x - other.x,
             y: self.y - other.y,
+            layer: self.layer - other.layer,
             coordinates: self.coordinates - other.coordinates,
         }
     }
@@ -163,6 +184,10 @@ impl Coordinates {
     pub fn origin() -> Coordinates {
         Coordinates { x: 0.0, y: 0.0 }
     }
+
+    pub fn length(self) -> f64 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
 }
 
 impl Div<f64> for Coordinates {
@@ -243,6 +268,8 @@ pub struct PolygonSide {
 pub struct Cell {
     pub offset: Offset,
     pub walls: Vec<bool>,
+    // The two opposite sides that a passage tunnels under, when the cell is a crossing in a weave maze
+    pub crossing: Option<(usize, usize)>,
     polygon: usize,
 }
 
@@ -250,13 +277,100 @@ impl Cell {
     pub fn is_open(&self) -> bool {
         self.walls.iter().any(|w| !*w)
     }
+
+    // Sides with a solid wall along them. The walls across the mouths of a tunnel stay up, as nothing opens
+    // into the cell there, but the passage runs under them so they aren't drawn.
+    pub fn drawn_walls(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.walls.len() - 2).filter(move |&side| self.walls[side] && self.tunnel_exit(side).is_none())
+    }
+
+    // The side a tunnel coming in under the given side comes out of
+    fn tunnel_exit(&self, side: usize) -> Option<usize> {
+        match self.crossing {
+            Some((a, b)) if a == side => Some(b),
+            Some((a, b)) if b == side => Some(a),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Verification {
+    pub cells: usize,
+    pub passages: usize,
+    // Cells that can't be reached from the first cell
+    pub unreachable: Vec<Offset>,
+    // Passages that close a loop
+    pub cycles: Vec<(Offset, Offset)>,
+    // Walls that are only open from one of the two cells
+    pub one_sided: Vec<(Offset, Offset)>,
+}
+
+impl Verification {
+    pub fn is_perfect(&self) -> bool {
+        self.unreachable.is_empty() && self.cycles.is_empty() && self.one_sided.is_empty() && self.passages + 1 == self.cells
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wrap {
+    None,
+    // The left and right edges join up, making a cylinder
+    Horizontal,
+    // Both pairs of opposite edges join up, making a torus
+    Both,
+}
+
+// Everything that decides the cells of a maze, apart from the boundary it is cut out of and where it is centered
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MazeSettings {
+    pub tiling: Tiling,
+    pub scaling: f64,
+    pub rotation: f64,
+    pub layers: usize,
+    pub wrap: Wrap,
+    pub inclusion: Inclusion,
+}
+
+// How much of a cell has to be inside the boundary for it to be part of the maze
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Inclusion {
+    AllCorners,
+    Centroid,
+    // Checked at points along the sides, so a boundary that only pokes into the middle of a cell can miss it
+    AnyOverlap,
+}
+
+// Points checked along each side of a cell for any overlap, not counting the corner it ends at
+const OVERLAP_SAMPLES: usize = 4;
+
+impl Inclusion {
+    fn includes(self, corners: &[Coordinates], inside: impl Fn(Coordinates) -> bool) -> bool {
+        let centroid = corners.iter().fold(Coordinates::origin(), |sum, &corner| sum + corner) / corners.len() as f64;
+        match self {
+            Inclusion::AllCorners => corners.iter().all(|&corner| inside(corner)),
+            Inclusion::Centroid => inside(centroid),
+            Inclusion::AnyOverlap => inside(centroid) || (0..corners.len()).any(|index| {
+                let (from, to) = (corners[index], corners[(index + 1) % corners.len()]);
+                (0..OVERLAP_SAMPLES).any(|step| inside(from + (to - from) * (step as f64 / OVERLAP_SAMPLES as f64)))
+            }),
+        }
+    }
 }
 
 pub struct Maze {
     pub cells: HashMap<Offset, Cell>, // Offset -> index in Tiling.polygons
     pub tiling: Tiling,
     pub scaling: f64,
-    pub rotation: f64
+    pub rotation: f64,
+    pub layers: usize,
+    pub wrap: Wrap,
+    pub inclusion: Inclusion,
+    // Translations of the tiling that the maze wraps around by, with their coordinates on screen.
+    // There can be fewer than the wrap asks for when the tiling doesn't line up with the edges.
+    periods: Vec<Offset>,
+    // Cells on each layer that were left out because the edge of the boundary cut them off from the rest
+    pub dropped: usize,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -294,46 +408,171 @@ pub fn scale(point: Coordinates, origin: Coordinates, scale: f64) -> Coordinates
     (point - origin) * scale + origin
 }
 
+fn times(offset: Offset, factor: isize) -> Offset {
+    Offset {
+        x: offset.x * factor,
+        y: offset.y * factor,
+        layer: 0,
+        coordinates: offset.coordinates * factor as f64,
+    }
+}
+
+// Whether the tile offset is a whole number of each of the periods added together
+fn is_multiple(offset: Offset, periods: &[Offset]) -> bool {
+    match periods {
+        [] => offset.x == 0 && offset.y == 0,
+        [period] => {
+            offset.x * period.y == offset.y * period.x &&
+                if period.x != 0 { offset.x % period.x == 0 } else { offset.y % period.y == 0 }
+        }
+        [first, second, ..] => {
+            let determinant = first.x * second.y - first.y * second.x;
+            (offset.x * second.y - offset.y * second.x) % determinant == 0 &&
+                (first.x * offset.y - first.y * offset.x) % determinant == 0
+        }
+    }
+}
+
+// Wrapping around a short period can bring a cell round to one of its own neighbors, or two of its neighbors round
+// to the same cell, which would leave it with two ways into one place
+fn wraps_cleanly(tiling: Tiling, periods: &[Offset]) -> bool {
+    tiling.polygons.iter().all(|polygon| {
+        let reached: Vec<Offset> = std::iter::once(polygon.offset)
+            .chain(polygon.sides.iter().map(|side| {
+                let tile_offset = match side.other_tile {
+                    0 => Offset::origin(),
+                    x => tiling.neighbors[x - 1],
+                };
+                tiling.polygons[side.other_polygon].offset + tile_offset
+            }))
+            .collect();
+
+        reached.iter().enumerate().all(|(index, &first)| {
+            reached[index + 1..].iter().all(|&second| first == second || !is_multiple(first - second, periods))
+        })
+    })
+}
+
+// The longest translation of the tiling that points exactly along the axis and fits in the given length.
+// It has to be at least two tiles long, and wrap around by it along with any periods already picked without giving
+// a cell the same neighbor twice.
+fn period(tiling: Tiling, scaling: f64, rotation: f64, axis: Coordinates, length: f64, existing: &[Offset]) -> Option<Offset> {
+    let screen = |offset: Offset| scale(rotate(offset.coordinates, Coordinates::origin(), rotation), Coordinates::origin(), scaling);
+    let cross = |a: Coordinates, b: Coordinates| a.x * b.y - a.y * b.x;
+
+    // Two independent translations, preferring a pair that reaches every tile
+    let (first, second) = tiling.neighbors.iter()
+        .flat_map(|&a| tiling.neighbors.iter().map(move |&b| (a, b)))
+        .filter(|(a, b)| cross(a.coordinates, b.coordinates).abs() > 1e-6)
+        .min_by_key(|(a, b)| (a.x * b.y - a.y * b.x).abs())?;
+    let (a, b) = (screen(first), screen(second));
+    let limit = (length * a.length().max(b.length()) / cross(a, b).abs()).ceil() as isize + 1;
+    let shortest = tiling.neighbors.iter().map(|&neighbor| screen(neighbor).length()).fold(f64::MAX, f64::min);
+    let tolerance = scaling * 1e-6;
+
+    let mut best: Option<(f64, Offset)> = None;
+    for i in -limit..=limit {
+        for j in -limit..=limit {
+            let translation = times(first, i) + times(second, j);
+            let coordinates = screen(translation);
+            let along = coordinates.x * axis.x + coordinates.y * axis.y;
+            let fits = along >= 2.0 * shortest && along <= length + tolerance;
+            if fits && cross(axis, coordinates).abs() < tolerance && best.is_none_or(|(longest, _)| along > longest) &&
+                wraps_cleanly(tiling, &[existing, &[translation]].concat()) {
+                best = Some((along, Offset { coordinates, ..translation }));
+            }
+        }
+    }
+
+    best.map(|(_, period)| period)
+}
+
 impl Maze {
-    pub fn new(tiling: Tiling, bounding_box: Rectangle, center: Coordinates, scaling: f64, rotation: f64) -> Maze {
+    pub fn new(settings: MazeSettings, boundary: &dyn Boundary, center: Coordinates) -> Maze {
+        let MazeSettings { tiling, scaling, rotation, layers, wrap, inclusion } = settings;
         let start = Offset {
             x: 0,
             y: 0,
+            layer: 0,
             coordinates: center,
         };
 
+        let bounding_box = boundary.bounding_box();
+
         debug_assert!(bounding_box.contains(start.coordinates), "Bounding box does not contain center!");
         debug_assert!(tiling.validate().is_ok(), "{}", tiling.validate().err().unwrap().message);
 
+        let middle = Coordinates {
+            x: bounding_box.x + bounding_box.width / 2.0,
+            y: bounding_box.y + bounding_box.height / 2.0,
+        };
+
+        let horizontal = match wrap {
+            Wrap::None => None,
+            _ => period(tiling, scaling, rotation, Coordinates { x: 1.0, y: 0.0 }, bounding_box.width, &[]),
+        };
+        let vertical = match wrap {
+            Wrap::Both => period(tiling, scaling, rotation, Coordinates { x: 0.0, y: 1.0 }, bounding_box.height, horizontal.as_slice()),
+            _ => None,
+        };
+
+        // Along a wrapped edge each cell must appear exactly once, so cells are picked by where they are rather than
+        // by fitting in the box. The window is nudged back a little so rounding can't let in both copies of a cell.
+        let within = |value: f64, start: f64, length: f64| {
+            let slack = length * 1e-6;
+            value >= start - slack && value < start + length - slack
+        };
+        // A wrapped axis has no edge, so only the position along the other one is checked against the boundary
+        let inside = |point: Coordinates| boundary.contains(Coordinates {
+            x: if horizontal.is_some() { middle.x } else { point.x },
+            y: if vertical.is_some() { middle.y } else { point.y },
+        });
+
+        // Every tile close enough to the bounding box to have a cell in it is searched, not just the ones joined to
+        // the center, so the largest piece is found wherever it is. Going one step further keeps the search in one piece.
+        // Only that piece is kept afterwards.
+        let reach = scaling * (
+            tiling.polygons.iter()
+                .flat_map(|polygon| polygon.corners.iter().map(move |corner| (polygon.offset.coordinates + *corner).length()))
+                .fold(0.0, f64::max) +
+            tiling.neighbors.iter().map(|neighbor| neighbor.coordinates.length()).fold(0.0, f64::max)
+        );
+        let near = |tile: Offset| {
+            let position = scale(rotate(tile.coordinates, center, rotation), center, scaling);
+            position.x >= bounding_box.x - reach && position.x <= bounding_box.x + bounding_box.width + reach &&
+                position.y >= bounding_box.y - reach && position.y <= bounding_box.y + bounding_box.height + reach
+        };
+
         let mut seen = HashSet::new();
         seen.insert(start);
         let mut queue = vec![start];
         let mut cells = HashMap::new();        
         
         while let Some(tile) = queue.pop() { 
-            let mut added = false;
             for (index, polygon) in tiling.polygons.iter().enumerate() {
                 let mut cell_offset = tile + polygon.offset;
                 cell_offset.coordinates = scale(rotate(cell_offset.coordinates, center, rotation), center, scaling);
 
-                if !polygon.corners.iter().all(|corner| {
-                    let corner_coordinates = scale(rotate(*corner, Coordinates::origin(), rotation), Coordinates::origin(), scaling);
-                    bounding_box.contains(corner_coordinates + cell_offset.coordinates)
-                }){
+                let corners: Vec<Coordinates> = polygon.corners.iter()
+                    .map(|corner| scale(rotate(*corner, Coordinates::origin(), rotation), Coordinates::origin(), scaling) + cell_offset.coordinates)
+                    .collect();
+                let in_window = horizontal.is_none_or(|period| within(cell_offset.coordinates.x, bounding_box.x, period.coordinates.x)) &&
+                    vertical.is_none_or(|period| within(cell_offset.coordinates.y, bounding_box.y, period.coordinates.y));
+                if !in_window || !inclusion.includes(&corners, inside) {
                     continue;
                 }
-                
-                added = true;
 
                 let inserted = cells.insert(cell_offset, Cell {
                     offset: cell_offset,
                     polygon: index,
-                    walls: vec![true; polygon.sides.len()],
+                    // The last two walls are the stairs down and up
+                    walls: vec![true; polygon.sides.len() + 2],
+                    crossing: None,
                 });
                 debug_assert!(inserted.is_none(), "Duplicate cell offset: {:?}", cell_offset)
             }
 
-            if added {
+            if near(tile) {
                 for &neighbor in tiling.neighbors {
                     let next_tile = tile + neighbor;
                     if seen.insert(next_tile) {
@@ -343,12 +582,39 @@ impl Maze {
             }
         }
 
-        debug_assert!(!cells.is_empty(), "Bounding box does not contain center!");
+        let periods = horizontal.into_iter().chain(vertical).collect();
+        let mut maze = Maze { cells, tiling, scaling, rotation, layers, wrap, inclusion, periods, dropped: 0 };
+
+        // The edge of the boundary can cut a few cells off from the rest, and those could never be reached
+        let largest: HashSet<Offset> = maze.regions().into_iter()
+            .max_by_key(|region| region.len())
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        maze.dropped = maze.cells.len() - largest.len();
+        maze.cells.retain(|offset, _| largest.contains(offset));
+
+        // Stack copies of the first layer on top of it
+        let first: Vec<Cell> = maze.cells.values().cloned().collect();
+        for layer in 1..layers {
+            for cell in &first {
+                let mut cell = cell.clone();
+                cell.offset.layer = layer;
+                maze.cells.insert(cell.offset, cell);
+            }
+        }
 
-        Maze { cells, tiling, scaling, rotation }
+        maze
+    }
+
+
+    // Every cell offset in a stable order, for anything that has to be reproducible from a seed
+    pub fn offsets(&self) -> Vec<Offset> {
+        let mut offsets: Vec<Offset> = self.cells.keys().copied().collect();
+        offsets.sort();
+        offsets
     }
 
-    
     pub fn cell_containing_point(&self, coordinates: Coordinates) -> Option<&Cell> {
         for cell in self.cells.values() {
             let polygon = self.get_polygon(cell);
@@ -364,14 +630,87 @@ impl Maze {
         let cell = &self.cells[&offset]; 
         self.tiling.polygons[cell.polygon].sides.iter()
             .enumerate()
+            // The sides a tunnel runs under lead into the tunnel, not this cell
+            .filter(move |(index, _)| cell.tunnel_exit(*index).is_none())
             .filter_map(move |(index, side)|  {
                 let offset = self.calculate_offset(cell, *side);
-                if self.cells.contains_key(&offset) {
-                    Some(Adjacency{index, offset})
-                } else {
-                    None
+                // Use the stored offset, which has the scaled and rotated coordinates
+                let other = self.cells.get(&offset)?;
+                match other.tunnel_exit(side.other_side_index) {
+                    // Go under the crossing and come out in the cell on the far side
+                    Some(exit) => {
+                        let exit_side = self.tiling.polygons[other.polygon].sides[exit];
+                        self.cells.get(&self.calculate_offset(other, exit_side)).map(|beyond| Adjacency{index, offset: beyond.offset})
+                    }
+                    None => Some(Adjacency{index, offset: other.offset}),
                 }
             })
+            .chain(self.stairs(cell))
+    }
+
+    // The same cell on the layers below and above, which are reached through the last two walls
+    fn stairs<'a>(&'a self, cell: &'a Cell) -> impl Iterator<Item = Adjacency> + 'a {
+        let sides = self.tiling.polygons[cell.polygon].sides.len();
+        (0..2).filter_map(move |step| {
+            let layer = if step == 0 { cell.offset.layer.checked_sub(1)? } else { cell.offset.layer + 1 };
+            let other = self.cells.get(&Offset { layer, ..cell.offset })?;
+            Some(Adjacency{index: sides + step, offset: other.offset})
+        })
+    }
+
+    // Every combination of moving up to one period either way along each wrapped edge, starting with not moving at all
+    pub fn translations(&self) -> Vec<Offset> {
+        let mut translations = vec![Offset::origin()];
+        for &period in &self.periods {
+            translations = translations.into_iter()
+                .flat_map(|translation| [translation, translation + period, translation - period])
+                .collect();
+        }
+        translations
+    }
+
+    // The shortest way from one cell to another on screen, which may go across a wrapped edge
+    pub fn displacement(&self, from: Offset, to: Offset) -> Coordinates {
+        self.translations().into_iter()
+            .map(|translation| to.coordinates + translation.coordinates - from.coordinates)
+            .min_by(|a, b| a.length().partial_cmp(&b.length()).unwrap())
+            .unwrap()
+    }
+
+    // The side facing directly away from the given one, if the cell's polygon has one
+    pub fn opposite_side(&self, offset: Offset, side: usize) -> Option<usize> {
+        let corners = self.tiling.polygons[self.cells[&offset].polygon].corners;
+        let half = corners.len() / 2;
+        // Stairs have nothing opposite them, and neither do sides of odd polygons
+        if side >= corners.len() || !corners.len().is_multiple_of(2) {
+            return None;
+        }
+
+        // Only polygons that are the same when turned half way round have sides that line up
+        let center = corners[0] + corners[half];
+        let symmetric = (1..half).all(|i| {
+            let other = corners[i] + corners[i + half];
+            (other.x - center.x).abs() < 1e-6 && (other.y - center.y).abs() < 1e-6
+        });
+
+        if symmetric {
+            Some((side + half) % corners.len())
+        } else {
+            None
+        }
+    }
+
+    // Runs a passage from one cell under a neighboring one and out to the cell on its far side
+    pub fn tunnel(&mut self, from: Offset, under: Offset, to: Offset) {
+        let entry = self.adjacencies(under).find(|adj| adj.offset == from).map(|adj| adj.index);
+        let exit = self.adjacencies(under).find(|adj| adj.offset == to).map(|adj| adj.index);
+
+        if let (Some(entry), Some(exit)) = (entry, exit) {
+            self.cells.get_mut(&under).unwrap().crossing = Some((entry, exit));
+            self.connect(from, to);
+        } else {
+            debug_assert!(false, "Cell {:?} is not between {:?} and {:?}", under, from, to);
+        }
     }
  
     pub fn connect(&mut self, cell: Offset, other: Offset) {
@@ -394,17 +733,115 @@ impl Maze {
         }
     }
     
-    fn connected_offsets<'a>(&'a self, cell: &'a Cell) -> impl Iterator<Item = Offset> + '_ {
-        self.tiling.polygons[cell.polygon].sides.iter()
-            .zip(&cell.walls)
-            .filter(|(_, b)| **b)
-            .map(|(side, _)| side)
-            .map(move |side| self.calculate_offset(cell, *side))
-            .filter(move |offset| self.cells.contains_key(&offset))
+    // Neighbors that can be reached without going through a wall
+    pub fn passages(&self, offset: Offset) -> impl Iterator<Item = Offset> + '_ {
+        let cell = &self.cells[&offset];
+        self.adjacencies(offset)
+            .filter(move |adjacency| !cell.walls[adjacency.index])
+            .map(|adjacency| adjacency.offset)
     }
 
     pub fn connected_cells<'a>(&'a self, cell: &'a Cell) -> impl Iterator<Item = &'a Cell> + '_ {
-        self.connected_offsets(cell).map(move |offset| &self.cells[&offset])
+        self.passages(cell.offset).map(move |offset| &self.cells[&offset])
+    }
+
+    pub fn degree(&self, offset: Offset) -> usize {
+        self.passages(offset).count()
+    }
+
+    pub fn is_dead_end(&self, offset: Offset) -> bool {
+        self.degree(offset) == 1
+    }
+
+    pub fn is_junction(&self, offset: Offset) -> bool {
+        self.degree(offset) > 2
+    }
+
+    // Groups of cells that can reach each other through passages, each in offset order
+    pub fn components(&self) -> Vec<Vec<Offset>> {
+        self.flood_fill(|offset| self.passages(offset))
+    }
+
+    // Groups of cells that would be reachable from each other if every wall was knocked down
+    fn regions(&self) -> Vec<Vec<Offset>> {
+        self.flood_fill(|offset| self.adjacencies(offset).map(|adjacency| adjacency.offset))
+    }
+
+    fn flood_fill<I: Iterator<Item = Offset>>(&self, neighbors: impl Fn(Offset) -> I) -> Vec<Vec<Offset>> {
+        let mut seen = HashSet::new();
+        let mut groups = Vec::new();
+
+        for offset in self.offsets() {
+            if !seen.insert(offset) {
+                continue;
+            }
+
+            let mut group = vec![offset];
+            let mut stack = vec![offset];
+            while let Some(current) = stack.pop() {
+                for next in neighbors(current) {
+                    if seen.insert(next) {
+                        group.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+
+            group.sort();
+            groups.push(group);
+        }
+
+        groups
+    }
+
+    // Checks that every cell can be reached in exactly one way, and that both sides agree on every wall
+    pub fn verify(&self) -> Verification {
+        let mut verification = Verification {
+            cells: self.cells.len(),
+            ..Default::default()
+        };
+        let mut reached = HashSet::new();
+
+        // Only passages that are open from both sides count, the rest are reported as one sided
+        let open = |a: Offset, b: Offset| self.passages(a).any(|offset| offset == b) && self.passages(b).any(|offset| offset == a);
+
+        let offsets = self.offsets();
+        for (index, &root) in offsets.iter().enumerate() {
+            if !reached.insert(root) {
+                continue;
+            }
+
+            let mut queue = VecDeque::from([root]);
+            while let Some(current) = queue.pop_front() {
+                if index > 0 {
+                    verification.unreachable.push(current);
+                }
+                for next in self.passages(current).filter(|&next| open(current, next)) {
+                    if reached.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        // Any passage between two cells that are already joined some other way closes a loop
+        let mut sets = DisjointSet::new();
+        for &offset in &offsets {
+            for adjacency in self.adjacencies(offset).filter(|adjacency| offset < adjacency.offset) {
+                let other = adjacency.offset;
+                if open(offset, other) {
+                    verification.passages += 1;
+                    if !sets.union(offset, other) {
+                        verification.cycles.push((offset, other));
+                    }
+                } else if self.passages(offset).any(|next| next == other) || self.passages(other).any(|next| next == offset) {
+                    verification.one_sided.push((offset, other));
+                }
+            }
+        }
+
+        verification.unreachable.sort();
+        verification
     }
 
     pub fn get_polygon(&self, cell: &Cell) -> TilePolygon {
@@ -420,8 +857,187 @@ impl Maze {
         };
         let offset = cell.offset - cell_polygon.offset + other_polygon.offset + tile_offset;
 
+        // A neighbor past a wrapped edge is the cell on the far side
+        if !self.cells.contains_key(&offset) {
+            if let Some(wrapped) = self.translations().into_iter().map(|translation| offset + translation).find(|wrapped| self.cells.contains_key(wrapped)) {
+                return wrapped;
+            }
+        }
+
         debug_assert!(offset != cell.offset, "Offset is the same as cell offset! Cell: {:?} Cell Polygon: {:?} Other Polygon: {:?} Other Tile: {:?}", cell.offset, cell_polygon, other_polygon, tile_offset);
 
         offset
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tilings::{self, SQUARE};
+
+    fn grid() -> Maze {
+        let area = Rectangle { x: 0.0, y: 0.0, width: 100.0, height: 100.0 };
+        let settings = MazeSettings { tiling: SQUARE, scaling: 20.0, rotation: 0.0, layers: 1, wrap: Wrap::None, inclusion: Inclusion::AllCorners };
+        Maze::new(settings, &area, Coordinates { x: 50.0, y: 50.0 })
+    }
+
+    // Joins every cell up along a breadth first spanning tree
+    fn perfect() -> Maze {
+        let mut maze = grid();
+        let root = maze.offsets()[0];
+        let mut seen = HashSet::from([root]);
+        let mut queue = VecDeque::from([root]);
+        while let Some(current) = queue.pop_front() {
+            let neighbors: Vec<Offset> = maze.adjacencies(current).map(|adjacency| adjacency.offset).collect();
+            for next in neighbors {
+                if seen.insert(next) {
+                    maze.connect(current, next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        maze
+    }
+
+    #[test]
+    fn verify_perfect_maze() {
+        let maze = perfect();
+        let verification = maze.verify();
+        assert!(maze.cells.len() > 4);
+        assert!(verification.is_perfect(), "{:?}", verification);
+        assert_eq!(verification.passages + 1, verification.cells);
+    }
+
+    #[test]
+    fn verify_maze_with_loop() {
+        let mut maze = perfect();
+        let (cell, other) = maze.offsets().into_iter()
+            .find_map(|offset| maze.adjacencies(offset).find(|adjacency| maze.cells[&offset].walls[adjacency.index]).map(|adjacency| (offset, adjacency.offset)))
+            .unwrap();
+        maze.connect(cell, other);
+
+        let verification = maze.verify();
+        assert!(!verification.is_perfect());
+        assert_eq!(verification.cycles.len(), 1);
+        assert!(verification.unreachable.is_empty());
+        assert!(verification.one_sided.is_empty());
+    }
+
+    #[test]
+    fn verify_disconnected_maze() {
+        let mut maze = perfect();
+        let cell = *maze.offsets().last().unwrap();
+        let passages: Vec<Offset> = maze.passages(cell).collect();
+        for other in passages {
+            maze.disconnect(cell, other);
+        }
+
+        let verification = maze.verify();
+        assert!(!verification.is_perfect());
+        assert_eq!(verification.unreachable, vec![cell]);
+        assert!(verification.cycles.is_empty());
+        assert!(verification.one_sided.is_empty());
+    }
+
+    #[test]
+    fn walled_cells_have_no_passages() {
+        let maze = grid();
+        for offset in maze.offsets() {
+            assert_eq!(maze.passages(offset).count(), 0);
+            assert_eq!(maze.connected_cells(&maze.cells[&offset]).count(), 0);
+            assert_eq!(maze.degree(offset), 0);
+            assert!(!maze.is_dead_end(offset) && !maze.is_junction(offset));
+        }
+        assert_eq!(maze.components().len(), maze.cells.len());
+    }
+
+    #[test]
+    fn passages_only_go_through_open_walls() {
+        let mut maze = grid();
+        let (center, neighbors) = maze.offsets().into_iter()
+            .map(|offset| (offset, maze.adjacencies(offset).map(|adjacency| adjacency.offset).collect::<Vec<Offset>>()))
+            .find(|(_, neighbors)| neighbors.len() == 4)
+            .unwrap();
+        for &neighbor in &neighbors {
+            maze.connect(center, neighbor);
+        }
+
+        let mut passages: Vec<Offset> = maze.passages(center).collect();
+        passages.sort();
+        let mut connected: Vec<Offset> = maze.connected_cells(&maze.cells[&center]).map(|cell| cell.offset).collect();
+        connected.sort();
+        let mut expected = neighbors.clone();
+        expected.sort();
+        assert_eq!(passages, expected);
+        assert_eq!(connected, expected);
+        assert_eq!(maze.degree(center), 4);
+        assert!(maze.is_junction(center) && !maze.is_dead_end(center));
+
+        for &neighbor in &neighbors {
+            assert_eq!(maze.passages(neighbor).collect::<Vec<Offset>>(), vec![center]);
+            assert_eq!(maze.connected_cells(&maze.cells[&neighbor]).map(|cell| cell.offset).collect::<Vec<Offset>>(), vec![center]);
+            assert!(maze.is_dead_end(neighbor) && !maze.is_junction(neighbor));
+        }
+
+        let components = maze.components();
+        assert_eq!(components.len(), maze.cells.len() - 4);
+        let joined = components.iter().find(|component| component.len() > 1).unwrap();
+        let mut expected = [neighbors, vec![center]].concat();
+        expected.sort();
+        assert_eq!(joined, &expected);
+    }
+
+    #[test]
+    fn perfect_maze_is_one_component() {
+        let maze = perfect();
+        let degrees: usize = maze.offsets().into_iter().map(|offset| maze.degree(offset)).sum();
+        assert_eq!(degrees, 2 * (maze.cells.len() - 1));
+        assert_eq!(maze.components(), vec![maze.offsets()]);
+    }
+
+    #[test]
+    fn tunnel_mouths_are_not_drawn_as_walls() {
+        let mut maze = grid();
+        let (from, under, to) = maze.offsets().into_iter()
+            .find_map(|under| {
+                let adjacency = maze.adjacencies(under).next()?;
+                let exit = maze.opposite_side(under, adjacency.index)?;
+                let to = maze.adjacencies(under).find(|adj| adj.index == exit)?.offset;
+                Some((adjacency.offset, under, to))
+            })
+            .unwrap();
+        maze.tunnel(from, under, to);
+
+        let cell = &maze.cells[&under];
+        let (entry, exit) = cell.crossing.unwrap();
+        let drawn: Vec<usize> = cell.drawn_walls().collect();
+        assert!(!drawn.contains(&entry) && !drawn.contains(&exit));
+        assert_eq!(drawn.len(), maze.get_polygon(cell).sides.len() - 2);
+    }
+
+    #[test]
+    fn wrapped_cells_have_distinct_neighbors() {
+        let tilings = [
+            tilings::SQUARE, tilings::HEX, tilings::TRIANGLE, tilings::TRUNCATED_SQUARE, tilings::TETRAKIS_SQUARE, tilings::SNUB_SQUARE,
+            tilings::CAIRO_PENTAGONAL, tilings::TRIHEXAGONAL, tilings::RHOMBILLE, tilings::TRUNCATED_HEX, tilings::TETRAKIS,
+        ];
+        let area = Rectangle { x: 0.0, y: 0.0, width: 500.0, height: 500.0 };
+        let center = Coordinates { x: 250.0, y: 250.0 };
+
+        for tiling in tilings {
+            for scaling in [15.0, 50.0, 100.0] {
+                for wrap in [Wrap::Horizontal, Wrap::Both] {
+                    let settings = MazeSettings { tiling, scaling, rotation: 0.0, layers: 1, wrap, inclusion: Inclusion::AllCorners };
+                    let maze = Maze::new(settings, &area, center);
+                    for offset in maze.offsets() {
+                        let mut neighbors: Vec<Offset> = maze.adjacencies(offset).map(|adjacency| adjacency.offset).collect();
+                        let count = neighbors.len();
+                        neighbors.sort();
+                        neighbors.dedup();
+                        assert_eq!(neighbors.len(), count, "{:?} at scale {} wrapped {:?}", offset, scaling, wrap);
+                        assert!(!neighbors.contains(&offset), "{:?} at scale {} wrapped {:?}", offset, scaling, wrap);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{maze::{Maze, Offset}, solvers::endpoints};
+
+// Moves that change direction by less than this many radians still count as going straight
+const TURN_TOLERANCE: f64 = 0.1;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Metrics {
+    pub cells: usize,
+    pub dead_ends: usize,
+    pub dead_end_ratio: f64,
+    pub junctions: usize,
+    // Longest shortest path, in passages, through the largest connected part of the maze
+    pub diameter: usize,
+    pub diameter_endpoints: Option<(Offset, Offset)>,
+    // Average number of passages between cells that are not simply part of a corridor
+    pub average_corridor_length: f64,
+    pub solution_length: Option<usize>,
+    pub solution_turns: Option<usize>,
+    // Average length of the branches leading to dead ends.
+    // High values mean a few long meandering rivers, low values lots of short stubs.
+    pub river_factor: f64,
+}
+
+impl Metrics {
+    pub fn of(maze: &Maze) -> Metrics {
+        let offsets = maze.offsets();
+        let dead_ends: Vec<Offset> = offsets.iter().copied().filter(|&offset| maze.is_dead_end(offset)).collect();
+        let junctions = offsets.iter().filter(|&&offset| maze.is_junction(offset)).count();
+
+        let (diameter, diameter_endpoints) = diameter(maze);
+
+        // Every corridor gets walked once from each end, which doesn't change the average
+        let corridors: Vec<usize> = offsets.iter()
+            .filter(|&&offset| maze.degree(offset) != 2)
+            .flat_map(|&offset| maze.passages(offset).map(move |next| corridor(maze, offset, next)))
+            .collect();
+
+        let branches: Vec<usize> = dead_ends.iter()
+            .map(|&offset| match maze.passages(offset).next() {
+                Some(next) => corridor(maze, offset, next),
+                None => 0,
+            })
+            .collect();
+
+        let solution = endpoints(maze).and_then(|(start, goal)| shortest_path(maze, start, goal));
+
+        Metrics {
+            cells: offsets.len(),
+            dead_ends: dead_ends.len(),
+            dead_end_ratio: ratio(dead_ends.len(), offsets.len()),
+            junctions,
+            diameter,
+            diameter_endpoints,
+            average_corridor_length: ratio(corridors.iter().sum(), corridors.len()),
+            solution_length: solution.as_ref().map(|path| path.len() - 1),
+            solution_turns: solution.as_ref().map(|path| turns(maze, path)),
+            river_factor: ratio(branches.iter().sum(), branches.len()),
+        }
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+// Follows a passage through cells with exactly two ways out, returning how many passages it has
+fn corridor(maze: &Maze, from: Offset, next: Offset) -> usize {
+    let mut length = 1;
+    let mut previous = from;
+    let mut current = next;
+    while current != from && maze.degree(current) == 2 {
+        let following = maze.passages(current).find(|&offset| offset != previous).unwrap();
+        previous = current;
+        current = following;
+        length += 1;
+    }
+    length
+}
+
+fn distances(maze: &Maze, start: Offset) -> HashMap<Offset, (usize, Offset)> {
+    let mut distances = HashMap::new();
+    distances.insert(start, (0, start));
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[&current].0;
+        for next in maze.passages(current) {
+            distances.entry(next).or_insert_with(|| {
+                queue.push_back(next);
+                (distance + 1, current)
+            });
+        }
+    }
+    distances
+}
+
+// The cell furthest away, taking the first in offset order on ties so the result is stable
+fn furthest(distances: &HashMap<Offset, (usize, Offset)>) -> (usize, Offset) {
+    distances.iter()
+        .map(|(&offset, &(distance, _))| (distance, offset))
+        .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+        .unwrap()
+}
+
+// Two breadth first searches, which is exact for perfect mazes and a close estimate otherwise
+fn diameter(maze: &Maze) -> (usize, Option<(Offset, Offset)>) {
+    let largest = maze.components().into_iter().max_by(|a, b| a.len().cmp(&b.len()).then(b.cmp(a)));
+
+    match largest {
+        Some(component) => {
+            let (_, first) = furthest(&distances(maze, component[0]));
+            let (diameter, second) = furthest(&distances(maze, first));
+            (diameter, Some((first, second)))
+        }
+        None => (0, None),
+    }
+}
+
+fn shortest_path(maze: &Maze, start: Offset, goal: Offset) -> Option<Vec<Offset>> {
+    let distances = distances(maze, start);
+    distances.get(&goal)?;
+
+    let mut path = vec![goal];
+    let mut current = goal;
+    while current != start {
+        current = distances[&current].1;
+        path.push(current);
+    }
+    path.reverse();
+    Some(path)
+}
+
+fn turns(maze: &Maze, path: &[Offset]) -> usize {
+    path.windows(3)
+        .filter(|cells| {
+            let incoming = maze.displacement(cells[0], cells[1]);
+            let outgoing = maze.displacement(cells[1], cells[2]);
+            let angle = (incoming.x * outgoing.y - incoming.y * outgoing.x)
+                .atan2(incoming.x * outgoing.x + incoming.y * outgoing.y);
+            angle.abs() > TURN_TOLERANCE
+        })
+        .count()
+}
@@ -0,0 +1,3 @@
+// Generators that change a maze after another one has finished with it, rather than carving one from scratch
+pub mod braid;
+pub mod sparseness;
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use rand::prelude::{SliceRandom, StdRng};
+
+use crate::{generators::{Generator, GeneratorOption, GeneratorUpdate}, maze::{Maze, Offset}};
+
+// Removes dead ends by knocking down one of their walls, which adds loops to the maze
+pub struct Braid {
+    dead_ends: Vec<Offset>,
+    // How many more dead ends to get rid of
+    remaining: usize,
+    rng: StdRng,
+    highlighted: Vec<Offset>,
+    finished: bool,
+}
+
+impl Generator for Braid {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            for cell in self.highlighted.drain(..) {
+                changes.push(GeneratorUpdate::Normal(cell));
+            }
+
+            while self.remaining > 0 {
+                let Some(cell) = self.dead_ends.pop() else { break };
+                // Braiding a neighbor may already have opened this one up
+                if !maze.is_dead_end(cell) {
+                    continue;
+                }
+
+                let walled: Vec<Offset> = maze.adjacencies(cell)
+                    .filter(|adjacency| maze.cells[&cell].walls[adjacency.index])
+                    .map(|adjacency| adjacency.offset)
+                    .collect();
+                // Joining two dead ends together gets rid of both at once
+                let dead_ends: Vec<Offset> = walled.iter().copied().filter(|&other| maze.is_dead_end(other)).collect();
+                let choices = if dead_ends.is_empty() { walled } else { dead_ends };
+
+                if let Some(&other) = choices.choose(&mut self.rng) {
+                    self.remaining = self.remaining.saturating_sub(if maze.is_dead_end(other) { 2 } else { 1 });
+                    changes.push(GeneratorUpdate::Connect(cell, other));
+                    changes.push(GeneratorUpdate::Primary(cell));
+                    changes.push(GeneratorUpdate::Primary(other));
+                    self.highlighted.push(cell);
+                    self.highlighted.push(other);
+                    break;
+                }
+            }
+
+            if self.remaining == 0 || self.dead_ends.is_empty() {
+                self.finished = true;
+                for cell in self.highlighted.drain(..) {
+                    changes.push(GeneratorUpdate::Normal(cell));
+                }
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, mut rng: StdRng, options: HashMap<&'static str, usize>) -> Self {
+        let mut dead_ends: Vec<Offset> = maze.offsets().into_iter().filter(|&cell| maze.is_dead_end(cell)).collect();
+        dead_ends.shuffle(&mut rng);
+        let remaining = (dead_ends.len() * options["Braid %"] + 50) / 100;
+
+        Braid {
+            dead_ends,
+            remaining,
+            rng,
+            highlighted: Vec::new(),
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![
+            GeneratorOption {
+                min: 0,
+                default: 100,
+                max: 100,
+                name: "Braid %",
+            },
+        ]
+    }
+}
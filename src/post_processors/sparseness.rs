@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use rand::prelude::StdRng;
+
+use crate::{generators::{Generator, GeneratorOption, GeneratorUpdate}, maze::{Maze, Offset}, solvers::endpoints};
+
+// Walls up dead ends, a layer at a time, leaving closed off cells behind.
+// The solver's start and goal are never closed, so enough iterations leave only the path between them.
+pub struct Sparseness {
+    dead_ends: Vec<Offset>,
+    iterations: usize,
+    kept: Vec<Offset>,
+    highlighted: Vec<Offset>,
+    finished: bool,
+}
+
+impl Sparseness {
+    fn dead_ends(&self, maze: &Maze) -> Vec<Offset> {
+        let mut dead_ends: Vec<Offset> = maze.offsets().into_iter()
+            .filter(|&cell| maze.is_dead_end(cell) && !self.kept.contains(&cell))
+            .collect();
+        dead_ends.reverse();
+        dead_ends
+    }
+}
+
+impl Generator for Sparseness {
+    fn step(&mut self, maze: &Maze) -> Vec<GeneratorUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            for cell in self.highlighted.drain(..) {
+                changes.push(GeneratorUpdate::Normal(cell));
+            }
+
+            // Each iteration only closes the dead ends there were when it started
+            if self.dead_ends.is_empty() && self.iterations > 0 {
+                self.dead_ends = self.dead_ends(maze);
+                self.iterations -= 1;
+            }
+
+            while let Some(cell) = self.dead_ends.pop() {
+                // Closing the other end of a two cell corridor leaves this one closed off already
+                if let Some(other) = maze.passages(cell).next() {
+                    changes.push(GeneratorUpdate::Disconnect(cell, other));
+                    changes.push(GeneratorUpdate::Primary(cell));
+                    self.highlighted.push(cell);
+                    break;
+                }
+            }
+
+            if self.dead_ends.is_empty() && (self.iterations == 0 || self.dead_ends(maze).is_empty()) {
+                self.finished = true;
+                for cell in self.highlighted.drain(..) {
+                    changes.push(GeneratorUpdate::Normal(cell));
+                }
+            }
+        }
+
+        if self.finished {
+            changes.push(GeneratorUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, _rng: StdRng, options: HashMap<&'static str, usize>) -> Self {
+        Sparseness {
+            dead_ends: Vec::new(),
+            iterations: options["Iterations"],
+            kept: endpoints(maze).map_or(Vec::new(), |(start, goal)| vec![start, goal]),
+            highlighted: Vec::new(),
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![
+            GeneratorOption {
+                min: 0,
+                default: 10,
+                max: 100,
+                name: "Iterations",
+            },
+        ]
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use crate::{generators::GeneratorOption, maze::{Maze, Offset}};
+
+pub mod a_star;
+pub mod breadth_first;
+pub mod dead_end_filling;
+pub mod depth_first;
+pub mod wall_follower;
+
+pub trait Solver {
+    fn init(maze: &Maze, start: Offset, goal: Offset, options: HashMap<&'static str, usize>) -> Self
+        where Self: Sized;
+    fn step(&mut self, maze: &Maze) -> Vec<SolverUpdate>;
+    fn options(maze: &Maze) -> Vec<GeneratorOption>
+        where Self: Sized;
+}
+
+type SolverInit = fn(&Maze, Offset, Offset, HashMap<&'static str, usize>) -> Box<dyn Solver>;
+
+// Like a GeneratorType, except that solvers are also told where to start and finish
+#[derive(Clone, Copy)]
+pub struct SolverType {
+    pub init: SolverInit,
+    pub options: fn(&Maze) -> Vec<GeneratorOption>,
+}
+
+impl SolverType {
+    pub fn of<S: Solver + 'static>() -> SolverType {
+        SolverType {
+            init: init_boxed::<S>,
+            options: S::options,
+        }
+    }
+}
+
+fn init_boxed<S: Solver + 'static>(maze: &Maze, start: Offset, goal: Offset, options: HashMap<&'static str, usize>) -> Box<dyn Solver> {
+    Box::new(S::init(maze, start, goal, options))
+}
+
+// Solvers run from the top left cell on screen to the bottom right one, going from the bottom layer to the top one.
+// Offsets follow the tiling rather than the screen, so on rotated mazes they can't be used to find the corners.
+// An empty maze has neither.
+pub fn endpoints(maze: &Maze) -> Option<(Offset, Offset)> {
+    let offsets = maze.offsets();
+    let diagonal = |offset: &&Offset| offset.coordinates.x + offset.coordinates.y;
+    let start = offsets.iter()
+        .filter(|offset| offset.layer == 0)
+        .min_by(|a, b| diagonal(a).total_cmp(&diagonal(b)))?;
+    let goal = offsets.iter()
+        .filter(|offset| offset.layer == maze.layers - 1)
+        .max_by(|a, b| diagonal(a).total_cmp(&diagonal(b)))?;
+    Some((*start, *goal))
+}
+
+pub enum SolverUpdate {
+    Visited(Offset),
+    Frontier(Offset),
+    Path(Offset),
+    Normal(Offset),
+    Finished()
+}
+
+// Follows the parent links back from the goal, marking the cells on the way as the solution
+pub fn path_updates(parents: &HashMap<Offset, Offset>, goal: Offset) -> Vec<SolverUpdate> {
+    let mut updates = vec![SolverUpdate::Path(goal)];
+    let mut current = goal;
+    while let Some(&parent) = parents.get(&current) {
+        updates.push(SolverUpdate::Path(parent));
+        current = parent;
+    }
+    updates
+}
@@ -0,0 +1,117 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap, HashSet}};
+
+use crate::{generators::GeneratorOption, maze::{Maze, Offset}};
+
+use super::{Solver, SolverUpdate, path_updates};
+
+struct Candidate {
+    estimate: f64,
+    cost: f64,
+    offset: Offset,
+}
+
+// Reversed so that the BinaryHeap pops the lowest estimate first
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.partial_cmp(&self.estimate).unwrap()
+            .then(self.cost.partial_cmp(&other.cost).unwrap())
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+pub struct AStar {
+    open: BinaryHeap<Candidate>,
+    closed: HashSet<Offset>,
+    costs: HashMap<Offset, f64>,
+    parents: HashMap<Offset, Offset>,
+    goal: Offset,
+    finished: bool,
+}
+
+impl Solver for AStar {
+    fn step(&mut self, maze: &Maze) -> Vec<SolverUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            // Cells are pushed again whenever a cheaper route is found, so skip the stale entries
+            while self.open.peek().is_some_and(|candidate| self.closed.contains(&candidate.offset)) {
+                self.open.pop();
+            }
+
+            if let Some(Candidate { cost, offset: cell, .. }) = self.open.pop() {
+                self.closed.insert(cell);
+                changes.push(SolverUpdate::Visited(cell));
+
+                if cell == self.goal {
+                    changes.extend(path_updates(&self.parents, cell));
+                    self.finished = true;
+                } else {
+                    for neighbor in maze.passages(cell) {
+                        if self.closed.contains(&neighbor) {
+                            continue;
+                        }
+                        // Cell centroids work as positions on every tiling, so straight line distance never overestimates.
+                        // On wrapped mazes it is measured the shortest way round.
+                        let neighbor_cost = cost + maze.displacement(cell, neighbor).length();
+                        if self.costs.get(&neighbor).is_none_or(|&known| neighbor_cost < known) {
+                            self.costs.insert(neighbor, neighbor_cost);
+                            self.parents.insert(neighbor, cell);
+                            self.open.push(Candidate {
+                                estimate: neighbor_cost + maze.displacement(neighbor, self.goal).length(),
+                                cost: neighbor_cost,
+                                offset: neighbor,
+                            });
+                            changes.push(SolverUpdate::Frontier(neighbor));
+                        }
+                    }
+                }
+            } else {
+                // The goal cannot be reached
+                self.finished = true;
+            }
+        }
+
+        if self.finished {
+            changes.push(SolverUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, start: Offset, goal: Offset, _options: HashMap<&'static str, usize>) -> Self {
+        let mut open = BinaryHeap::new();
+        open.push(Candidate {
+            estimate: maze.displacement(start, goal).length(),
+            cost: 0.0,
+            offset: start,
+        });
+        let mut costs = HashMap::new();
+        costs.insert(start, 0.0);
+
+        AStar {
+            open,
+            closed: HashSet::new(),
+            costs,
+            parents: HashMap::new(),
+            goal,
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{generators::GeneratorOption, maze::{Maze, Offset}};
+
+use super::{Solver, SolverUpdate, path_updates};
+
+pub struct BreadthFirst {
+    queue: VecDeque<Offset>,
+    seen: HashSet<Offset>,
+    parents: HashMap<Offset, Offset>,
+    goal: Offset,
+    finished: bool,
+}
+
+impl Solver for BreadthFirst {
+    fn step(&mut self, maze: &Maze) -> Vec<SolverUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            if let Some(cell) = self.queue.pop_front() {
+                changes.push(SolverUpdate::Visited(cell));
+
+                if cell == self.goal {
+                    changes.extend(path_updates(&self.parents, cell));
+                    self.finished = true;
+                } else {
+                    for neighbor in maze.passages(cell) {
+                        if self.seen.insert(neighbor) {
+                            self.parents.insert(neighbor, cell);
+                            self.queue.push_back(neighbor);
+                            changes.push(SolverUpdate::Frontier(neighbor));
+                        }
+                    }
+                }
+            } else {
+                // The goal cannot be reached
+                self.finished = true;
+            }
+        }
+
+        if self.finished {
+            changes.push(SolverUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(_maze: &Maze, start: Offset, goal: Offset, _options: HashMap<&'static str, usize>) -> Self {
+        let mut seen = HashSet::new();
+        seen.insert(start);
+
+        BreadthFirst {
+            queue: VecDeque::from(vec![start]),
+            seen,
+            parents: HashMap::new(),
+            goal,
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
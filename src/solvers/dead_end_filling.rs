@@ -0,0 +1,82 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{generators::GeneratorOption, maze::{Maze, Offset}};
+
+use super::{Solver, SolverUpdate};
+
+pub struct DeadEndFilling {
+    dead_ends: Vec<Offset>,
+    filled: HashSet<Offset>,
+    start: Offset,
+    goal: Offset,
+    finished: bool,
+}
+
+impl DeadEndFilling {
+    fn is_dead_end(&self, maze: &Maze, cell: Offset) -> bool {
+        cell != self.start && cell != self.goal && !self.filled.contains(&cell) &&
+            maze.passages(cell).filter(|neighbor| !self.filled.contains(neighbor)).count() <= 1
+    }
+}
+
+impl Solver for DeadEndFilling {
+    fn step(&mut self, maze: &Maze) -> Vec<SolverUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            // Fill one dead end per step, following it back until it reaches a junction
+            while let Some(cell) = self.dead_ends.pop() {
+                if !self.is_dead_end(maze, cell) {
+                    continue;
+                }
+
+                self.filled.insert(cell);
+                changes.push(SolverUpdate::Visited(cell));
+
+                let next = maze.passages(cell).find(|neighbor| !self.filled.contains(neighbor));
+                if let Some(next) = next {
+                    if self.is_dead_end(maze, next) {
+                        self.dead_ends.push(next);
+                    }
+                }
+                break;
+            }
+
+            if self.dead_ends.is_empty() {
+                // Whatever is left over is the solution, along with any loops it passes through
+                for cell in maze.offsets() {
+                    if !self.filled.contains(&cell) && maze.cells[&cell].is_open() {
+                        changes.push(SolverUpdate::Path(cell));
+                    }
+                }
+                self.finished = true;
+            }
+        }
+
+        if self.finished {
+            changes.push(SolverUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, start: Offset, goal: Offset, _options: HashMap<&'static str, usize>) -> Self {
+        let mut solver = DeadEndFilling {
+            dead_ends: Vec::new(),
+            filled: HashSet::new(),
+            start,
+            goal,
+            finished: false,
+        };
+        let mut dead_ends: Vec<Offset> = maze.offsets().into_iter()
+            .filter(|&cell| solver.is_dead_end(maze, cell))
+            .collect();
+        dead_ends.reverse();
+        solver.dead_ends = dead_ends;
+        solver
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
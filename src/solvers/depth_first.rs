@@ -0,0 +1,67 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{generators::GeneratorOption, maze::{Maze, Offset}};
+
+use super::{Solver, SolverUpdate, path_updates};
+
+pub struct DepthFirst {
+    stack: Vec<Offset>,
+    visited: HashSet<Offset>,
+    parents: HashMap<Offset, Offset>,
+    goal: Offset,
+    finished: bool,
+}
+
+impl Solver for DepthFirst {
+    fn step(&mut self, maze: &Maze) -> Vec<SolverUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            // Cells can be pushed more than once before they are visited
+            while self.stack.last().is_some_and(|cell| self.visited.contains(cell)) {
+                self.stack.pop();
+            }
+
+            if let Some(cell) = self.stack.pop() {
+                self.visited.insert(cell);
+                changes.push(SolverUpdate::Visited(cell));
+
+                if cell == self.goal {
+                    changes.extend(path_updates(&self.parents, cell));
+                    self.finished = true;
+                } else {
+                    for neighbor in maze.passages(cell) {
+                        if !self.visited.contains(&neighbor) {
+                            self.parents.insert(neighbor, cell);
+                            self.stack.push(neighbor);
+                            changes.push(SolverUpdate::Frontier(neighbor));
+                        }
+                    }
+                }
+            } else {
+                // The goal cannot be reached
+                self.finished = true;
+            }
+        }
+
+        if self.finished {
+            changes.push(SolverUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(_maze: &Maze, start: Offset, goal: Offset, _options: HashMap<&'static str, usize>) -> Self {
+        DepthFirst {
+            stack: vec![start],
+            visited: HashSet::new(),
+            parents: HashMap::new(),
+            goal,
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![]
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{generators::GeneratorOption, maze::{Maze, Offset}};
+
+use super::{Solver, SolverUpdate};
+
+pub struct WallFollower {
+    current: Offset,
+    // The side of the current cell that it was entered through, or the stairs it came up or down
+    entry: usize,
+    right_hand: bool,
+    trail: Vec<Offset>,
+    trail_indexes: HashMap<Offset, usize>,
+    seen: HashSet<(Offset, usize)>,
+    goal: Offset,
+    finished: bool,
+}
+
+// Whether the sides of the cell's polygon go clockwise on screen, which decides which way is left
+fn clockwise(maze: &Maze, cell: Offset) -> bool {
+    let corners = maze.get_polygon(&maze.cells[&cell]).corners;
+    let area: f64 = (0..corners.len())
+        .map(|i| {
+            let j = (i + 1) % corners.len();
+            corners[i].x * corners[j].y - corners[j].x * corners[i].y
+        })
+        .sum();
+    // Screen coordinates point down, so a positive area is clockwise
+    area > 0.0
+}
+
+// The ways out of a cell in the order the hand meets them going round it. Stairs aren't on the wall, so they
+// come at a fixed point after the last side. Using the same order on every visit means a perfect maze still
+// gets walked all the way round, whichever layer the goal is on.
+fn turning_order(maze: &Maze, cell: Offset, right_hand: bool) -> Vec<usize> {
+    let sides = maze.get_polygon(&maze.cells[&cell]).sides.len();
+    let left = clockwise(maze, cell) != right_hand;
+    let mut order: Vec<usize> = if left { (0..sides).collect() } else { (0..sides).rev().collect() };
+    order.extend([sides, sides + 1]);
+    order
+}
+
+impl WallFollower {
+    // Tries each way out in turn starting from the one after the entry, so the hand stays on the same wall.
+    // The entry comes last, which turns the follower around at a dead end.
+    fn next_side(&self, maze: &Maze) -> Option<usize> {
+        let cell = &maze.cells[&self.current];
+        let order = turning_order(maze, self.current, self.right_hand);
+        let entry = order.iter().position(|&side| side == self.entry).unwrap_or(0);
+
+        (1..=order.len())
+            .map(|turn| order[(entry + turn) % order.len()])
+            .find(|&side| !cell.walls[side])
+    }
+
+    fn move_to(&mut self, next: Offset, changes: &mut Vec<SolverUpdate>) {
+        if let Some(&index) = self.trail_indexes.get(&next) {
+            // Came back to a cell on the trail, so everything since then was a dead end
+            for cell in self.trail.drain(index + 1..) {
+                self.trail_indexes.remove(&cell);
+            }
+        } else {
+            self.trail_indexes.insert(next, self.trail.len());
+            self.trail.push(next);
+        }
+        changes.push(SolverUpdate::Visited(self.current));
+        changes.push(SolverUpdate::Frontier(next));
+        self.current = next;
+    }
+}
+
+impl Solver for WallFollower {
+    fn step(&mut self, maze: &Maze) -> Vec<SolverUpdate> {
+        let mut changes = Vec::new();
+
+        if !self.finished {
+            if self.current == self.goal {
+                for &cell in &self.trail {
+                    changes.push(SolverUpdate::Path(cell));
+                }
+                self.finished = true;
+            } else if !self.seen.insert((self.current, self.entry)) {
+                // Going round in circles, so the goal is on a part of the maze this wall never touches
+                changes.push(SolverUpdate::Visited(self.current));
+                self.finished = true;
+            } else if let Some(side) = self.next_side(maze) {
+                let next = maze.adjacencies(self.current).find(|adjacency| adjacency.index == side).unwrap().offset;
+                let entry = maze.adjacencies(next).find(|adjacency| adjacency.offset == self.current).unwrap().index;
+                self.move_to(next, &mut changes);
+                self.entry = entry;
+            } else {
+                // Walled in on every side
+                self.finished = true;
+            }
+        }
+
+        if self.finished {
+            changes.push(SolverUpdate::Finished());
+        }
+
+        changes
+    }
+
+    fn init(maze: &Maze, start: Offset, goal: Offset, options: HashMap<&'static str, usize>) -> Self {
+        // Start as though the follower came in from outside the maze, or failing that through any wall
+        let cell = &maze.cells[&start];
+        let sides = maze.get_polygon(cell).sides.len();
+        let neighbors: HashSet<usize> = maze.adjacencies(start).map(|adjacency| adjacency.index).collect();
+        let entry = (0..sides).find(|side| !neighbors.contains(side))
+            .or_else(|| (0..sides).find(|&side| cell.walls[side]))
+            .unwrap_or(0);
+
+        let mut trail_indexes = HashMap::new();
+        trail_indexes.insert(start, 0);
+
+        WallFollower {
+            current: start,
+            entry,
+            right_hand: options["Right Hand"] == 1,
+            trail: vec![start],
+            trail_indexes,
+            seen: HashSet::new(),
+            goal,
+            finished: false,
+        }
+    }
+
+    fn options(_maze: &Maze) -> Vec<GeneratorOption> {
+        vec![
+            GeneratorOption {
+                min: 0,
+                default: 0,
+                max: 1,
+                name: "Right Hand",
+            },
+        ]
+    }
+}
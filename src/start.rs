@@ -1,30 +1,60 @@
 #[cfg(console_error_panic_hook)]
 extern crate console_error_panic_hook;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use rand::thread_rng;
+use rand::{Rng, SeedableRng, prelude::StdRng, thread_rng};
 use wasm_bindgen::{JsCast, prelude::*};
 use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
 
-use crate::{generators::{Generator, GeneratorUpdate, recursive_division::RecursiveDivision}, maze::*, tilings};
+use crate::{bitmap::Bitmap, boundaries::{Boundary, Circle, Ellipse, Polygon, RegularPolygon}, metrics::Metrics, post_processors::{braid::Braid, sparseness::Sparseness}, generators::{Generator, GeneratorOption, GeneratorType, GeneratorUpdate, aldous_broder::AldousBroder, binary_tree::BinaryTree, eller::Eller, growing_tree::GrowingTree, hunt_and_kill::HuntAndKill, kruskal::Kruskal, prim::Prim, recursive_backtracker::RecursiveBacktracker, recursive_division::RecursiveDivision, sidewinder::Sidewinder, weave::Weave, wilson::Wilson}, maze::*, solvers::{Solver, SolverType, SolverUpdate, a_star::AStar, breadth_first::BreadthFirst, dead_end_filling::DeadEndFilling, depth_first::DepthFirst, endpoints, wall_follower::WallFollower}, tilings};
 
 #[wasm_bindgen]
 pub struct CanvasData {
     canvas: CanvasRenderingContext2d,
     bounding_box: Rectangle,
+    // The shape the maze is cut out of, which fits inside the bounding box
+    boundary: Box<dyn Boundary>,
     center: Coordinates,
+    // What the maze was built with, the exports change one of these and then rebuild it
+    settings: MazeSettings,
     maze: Maze,
+    // The layer of the maze that is drawn
+    layer: usize,
+    seed: u32,
     finished: bool,
+    generator_type: GeneratorType,
     generator: Box<dyn Generator>,
+    option_values: HashMap<&'static str, usize>,
+    post_processor_type: Option<GeneratorType>,
+    post_processor: Option<Box<dyn Generator>>,
+    post_processor_values: HashMap<&'static str, usize>,
+    post_processed: bool,
     primary: HashSet<Offset>,
     secondary: HashSet<Offset>,
+    solver_type: Option<SolverType>,
+    solver: Option<Box<dyn Solver>>,
+    solver_values: HashMap<&'static str, usize>,
+    solved: bool,
+    visited: HashSet<Offset>,
+    frontier: HashSet<Offset>,
+    path: HashSet<Offset>,
 }
 
 static HIGHLIGHT: &str = "#FAD02C";
 static SECONDARY_HIGHLIGHT: &str = "#90ADC6";
+static VISITED: &str = "#C5D9C0";
+static FRONTIER: &str = "#F3A683";
+static PATH: &str = "#E15A5A";
 static FILLED: &str = "#333652";
 static OPEN: &str = "#E9EAEc";
+static STAIRS: &str = "#333652";
+
+// Size of the stair arrows, relative to the scale of the maze
+const STAIR_SIZE: f64 = 0.15;
+
+// How far in from the corners of a crossing the walls of the tunnel under it are drawn
+const TUNNEL_INSET: f64 = 0.25;
 
 impl CanvasData {
     pub fn new(canvas: HtmlCanvasElement, scale: f64, rotation: f64) -> Self {
@@ -46,20 +76,40 @@ impl CanvasData {
             y: bounding_box.height / 2.0,
         };
     
-        let maze = Maze::new(tiling, bounding_box, center, scale, rotation);
+        let settings = MazeSettings { tiling, scaling: scale, rotation, layers: 1, wrap: Wrap::None, inclusion: Inclusion::AllCorners };
+        let maze = Maze::new(settings, &bounding_box, center);
         
-        let options = RecursiveDivision::options(&maze);
-        let option_values = options.iter().map(|option| (option.name, option.default)).collect();
+        let generator_type = generators().into_iter().find_map(|(name, generator)|if name == "Recursive Backtracker" {Some(generator)} else {None}).unwrap();
+        let options = (generator_type.options)(&maze);
+        let option_values = HashMap::new();
+        let seed = thread_rng().gen();
 
         Self {
             canvas: canvas_rendering,
             bounding_box,
-            generator: Box::new(RecursiveDivision::init(&maze, thread_rng(), option_values)),
+            boundary: Box::new(bounding_box),
+            generator_type,
+            generator: (generator_type.init)(&maze, StdRng::seed_from_u64(seed as u64), resolve_options(&options, &option_values)),
+            option_values,
+            settings,
             maze,
+            layer: 0,
+            seed,
             center,
             finished: false,
+            post_processor_type: None,
+            post_processor: None,
+            post_processor_values: HashMap::new(),
+            post_processed: false,
             primary: HashSet::new(),
             secondary: HashSet::new(),
+            solver_type: None,
+            solver: None,
+            solver_values: HashMap::new(),
+            solved: false,
+            visited: HashSet::new(),
+            frontier: HashSet::new(),
+            path: HashSet::new(),
         }
     }
 
@@ -86,6 +136,12 @@ impl CanvasData {
                     renders.insert(a);
                     renders.insert(b);
                 }
+                GeneratorUpdate::Tunnel(a, under, b) => {
+                    self.maze.tunnel(a, under, b);
+                    renders.insert(a);
+                    renders.insert(under);
+                    renders.insert(b);
+                }
                 GeneratorUpdate::Finished() => {
                     self.finished = true;
                 }
@@ -109,23 +165,77 @@ impl CanvasData {
         renders
     }
 
+    pub fn process_solver_updates(&mut self, updates: Vec<SolverUpdate>) -> HashSet<Offset> {
+        let mut renders = HashSet::new();
+        for update in updates {
+            match update {
+                SolverUpdate::Finished() => {
+                    self.solved = true;
+                }
+                SolverUpdate::Normal(cell) => {
+                    renders.insert(cell);
+                    self.visited.remove(&cell);
+                    self.frontier.remove(&cell);
+                    self.path.remove(&cell);
+                }
+                SolverUpdate::Visited(cell) => {
+                    renders.insert(cell);
+                    self.visited.insert(cell);
+                    self.frontier.remove(&cell);
+                    self.path.remove(&cell);
+                }
+                SolverUpdate::Frontier(cell) => {
+                    renders.insert(cell);
+                    self.visited.remove(&cell);
+                    self.frontier.insert(cell);
+                    self.path.remove(&cell);
+                }
+                SolverUpdate::Path(cell) => {
+                    renders.insert(cell);
+                    self.visited.remove(&cell);
+                    self.frontier.remove(&cell);
+                    self.path.insert(cell);
+                }
+            }
+        }
+        renders
+    }
+
     fn render_cell(&self, cell: &Cell) {
+        if cell.offset.layer != self.layer {
+            return;
+        }
+
         let fill = if self.primary.contains(&cell.offset) {
             HIGHLIGHT
         } else if self.secondary.contains(&cell.offset) {
             SECONDARY_HIGHLIGHT
+        } else if self.path.contains(&cell.offset) {
+            PATH
+        } else if self.frontier.contains(&cell.offset) {
+            FRONTIER
+        } else if self.visited.contains(&cell.offset) {
+            VISITED
         } else if cell.is_open() {
             OPEN
         } else {
             FILLED
         };
 
+        // Cells along a wrapped edge are drawn again past the opposite edge, where their neighbors are
+        for translation in self.maze.translations() {
+            self.draw_cell(cell, fill, cell.offset.coordinates + translation.coordinates);
+        }
+    }
+
+    fn draw_cell(&self, cell: &Cell, fill: &str, center: Coordinates) {
         let polygon = self.maze.get_polygon(cell);
+        let position = |corner: Coordinates| center + scale(rotate(corner, Coordinates::origin(), self.maze.rotation), Coordinates::origin(), self.maze.scaling);
 
         // Fill:
         self.canvas.begin_path();
         for corner in polygon.corners {
-            let corner_coordinates = cell.offset.coordinates + scale(rotate(*corner, Coordinates::origin(), self.maze.rotation), Coordinates::origin(), self.maze.scaling);
+            let corner_coordinates = position(*corner);
             self.canvas.line_to(corner_coordinates.x, corner_coordinates.y); 
         }
         self.canvas.close_path();
@@ -133,36 +243,125 @@ impl CanvasData {
         self.canvas.fill();
 
         // Walls:
-        for (i, corner) in polygon.corners.iter().enumerate() {
-            if cell.walls[i] {
-                let next_corner = polygon.corners[(i+1) % polygon.corners.len()];
+        for i in cell.drawn_walls() {
+            let next_corner = polygon.corners[(i+1) % polygon.corners.len()];
+            self.canvas.begin_path();
+            let start_position = position(polygon.corners[i]);
+            let stop_position = position(next_corner);
+            self.canvas.move_to(start_position.x, start_position.y);
+            self.canvas.line_to(stop_position.x, stop_position.y);
+            self.canvas.stroke();
+        }
+
+        // Stairs, drawn as arrows pointing to the layer they lead to
+        let sides = polygon.sides.len();
+        for (wall, direction) in [(sides, 1.0), (sides + 1, -1.0)] {
+            if !cell.walls[wall] {
+                let size = self.maze.scaling * STAIR_SIZE;
+                let tip = center + Coordinates { x: 0.0, y: direction * size * 1.5 };
+                let base = center + Coordinates { x: 0.0, y: direction * size * 0.5 };
+                self.canvas.begin_path();
+                self.canvas.move_to(tip.x, tip.y);
+                self.canvas.line_to(base.x - size, base.y);
+                self.canvas.line_to(base.x + size, base.y);
+                self.canvas.close_path();
+                self.canvas.set_fill_style_str(STAIRS);
+                self.canvas.fill();
+            }
+        }
+
+        // Under passage, drawn as dashed walls running between the two sides the tunnel goes through
+        if let Some((a, b)) = cell.crossing {
+            let corners = polygon.corners;
+            let count = corners.len();
+            let along = |side: usize, fraction: f64| corners[side] + (corners[(side + 1) % count] - corners[side]) * fraction;
+
+            self.canvas.set_line_dash(&js_sys::Array::of2(&JsValue::from(3.0), &JsValue::from(3.0))).unwrap();
+            for fraction in [TUNNEL_INSET, 1.0 - TUNNEL_INSET] {
+                let start_position = position(along(a, fraction));
+                let stop_position = position(along(b, 1.0 - fraction));
                 self.canvas.begin_path();
-                let start_position = cell.offset.coordinates + scale(rotate(*corner, Coordinates::origin(), self.maze.rotation), Coordinates::origin(), self.maze.scaling);
-                let stop_position = cell.offset.coordinates + scale(rotate(next_corner, Coordinates::origin(), self.maze.rotation), Coordinates::origin(), self.maze.scaling);
                 self.canvas.move_to(start_position.x, start_position.y);
                 self.canvas.line_to(stop_position.x, stop_position.y);
                 self.canvas.stroke();
             }
+            self.canvas.set_line_dash(&js_sys::Array::new()).unwrap();
         }
-        
+    }
+
+    // Builds the maze again after any of its settings, the boundary or the seed have changed
+    pub fn rebuild(&mut self) {
+        self.reset(Maze::new(self.settings, self.boundary.as_ref(), self.center));
     }
 
     pub fn reset(&mut self, maze: Maze) {
         self.maze = maze;
-        let options = RecursiveDivision::options(&self.maze);
-        let option_values = options.iter().map(|option| (option.name, option.default)).collect();
-        self.generator = Box::new(RecursiveDivision::init(&self.maze, thread_rng(), option_values));
+        self.layer = self.layer.min(self.maze.layers - 1);
+        let options = (self.generator_type.options)(&self.maze);
+        self.generator = (self.generator_type.init)(&self.maze, StdRng::seed_from_u64(self.seed as u64), resolve_options(&options, &self.option_values));
         self.finished = false;
+        self.post_processor = None;
+        self.post_processed = false;
         self.primary.clear();
         self.secondary.clear();
+        self.reset_solver();
+    }
+
+    // Clears any solving progress, leaving the maze as it is
+    pub fn reset_solver(&mut self) {
+        self.solver = None;
+        self.solved = false;
+        self.visited.clear();
+        self.frontier.clear();
+        self.path.clear();
         self.render();
     }
 
     pub fn step(&mut self, iterations: u32) -> bool {
         let mut renders = vec![];
         for _ in 0..iterations {
-            let updates = self.generator.step(&mut self.maze);
-            renders.extend(self.process_updates(updates));
+            if !self.finished {
+                let updates = self.generator.step(&self.maze);
+                renders.extend(self.process_updates(updates));
+
+                // Catch generators that leave loops, unreachable cells or mismatched walls behind
+                if cfg!(debug_assertions) && self.finished && !self.maze.cells.is_empty() {
+                    let verification = self.maze.verify();
+                    assert!(verification.is_perfect(), "Generator left an imperfect maze: {:?}", verification);
+                }
+            } else if !self.post_processed {
+                if let Some(post_processor) = &mut self.post_processor {
+                    let updates = post_processor.step(&self.maze);
+                    self.post_processed = updates.iter().any(|update| matches!(update, GeneratorUpdate::Finished()));
+                    renders.extend(self.process_updates(updates));
+                } else if let Some(post_processor_type) = self.post_processor_type {
+                    let options = (post_processor_type.options)(&self.maze);
+                    let option_values = resolve_options(&options, &self.post_processor_values);
+                    self.post_processor = Some((post_processor_type.init)(&self.maze, StdRng::seed_from_u64(self.seed as u64), option_values));
+                } else {
+                    self.post_processed = true;
+                }
+            } else if let Some(solver) = &mut self.solver {
+                if self.solved {
+                    break;
+                }
+                let updates = solver.step(&self.maze);
+                renders.extend(self.process_solver_updates(updates));
+            } else if let Some(solver_type) = self.solver_type {
+                // The solver can only start once the maze is complete, and an empty maze has nothing to solve
+                match endpoints(&self.maze) {
+                    Some((start, goal)) => {
+                        let options = (solver_type.options)(&self.maze);
+                        self.solver = Some((solver_type.init)(&self.maze, start, goal, resolve_options(&options, &self.solver_values)));
+                    }
+                    None => {
+                        self.solved = true;
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
         }
         
         for render in renders {
@@ -171,7 +370,7 @@ impl CanvasData {
             }
         }
 
-        self.finished
+        self.finished && self.post_processed && (self.solved || self.solver_type.is_none())
     }
 }
 
@@ -181,6 +380,17 @@ pub fn step(canvas_data: &mut CanvasData, iterations: u32) -> bool {
     canvas_data.step(iterations)
 }
 
+// The chosen value for each option, falling back to the default.  A new maze can change the limits, so values are clamped.
+// This doesn't use clamp, which panics if an option's limits ever cross, the maximum wins instead.
+fn resolve_options(options: &[GeneratorOption], chosen: &HashMap<&'static str, usize>) -> HashMap<&'static str, usize> {
+    options.iter()
+        .map(|option| {
+            let value = chosen.get(option.name).map_or(option.default, |value| (*value).max(option.min).min(option.max));
+            (option.name, value)
+        })
+        .collect()
+}
+
 fn tilings() -> Vec<(&'static str, Tiling)> {
     vec![
         ("Square", tilings::SQUARE),
@@ -197,6 +407,73 @@ fn tilings() -> Vec<(&'static str, Tiling)> {
     ]
 }
 
+fn wraps() -> Vec<(&'static str, Wrap)> {
+    vec![
+        ("None", Wrap::None),
+        ("Horizontal", Wrap::Horizontal),
+        ("Both", Wrap::Both),
+    ]
+}
+
+// Shapes centered on the canvas and as large as will fit
+fn boundaries(bounding_box: Rectangle) -> Vec<(&'static str, Box<dyn Boundary>)> {
+    let center = Coordinates {
+        x: bounding_box.x + bounding_box.width / 2.0,
+        y: bounding_box.y + bounding_box.height / 2.0,
+    };
+    let radius = bounding_box.width.min(bounding_box.height) / 2.0;
+    vec![
+        ("Rectangle", Box::new(bounding_box)),
+        ("Circle", Box::new(Circle { center, radius })),
+        ("Ellipse", Box::new(Ellipse { center, radius_x: bounding_box.width / 2.0, radius_y: bounding_box.height / 3.0 })),
+        ("Hexagon", Box::new(RegularPolygon { center, radius, sides: 6, rotation: 0.0 })),
+        ("Star", Box::new(Polygon::star(center, radius, radius * 0.45, 5))),
+        ("Heart", Box::new(Polygon::heart(center, radius))),
+    ]
+}
+
+fn inclusions() -> Vec<(&'static str, Inclusion)> {
+    vec![
+        ("All Corners", Inclusion::AllCorners),
+        ("Centroid", Inclusion::Centroid),
+        ("Any Overlap", Inclusion::AnyOverlap),
+    ]
+}
+
+fn generators() -> Vec<(&'static str, GeneratorType)> {
+    vec![
+        ("Recursive Backtracker", GeneratorType::of::<RecursiveBacktracker>()),
+        ("Kruskal", GeneratorType::of::<Kruskal>()),
+        ("Prim", GeneratorType::of::<Prim>()),
+        ("Wilson", GeneratorType::of::<Wilson>()),
+        ("Aldous-Broder", GeneratorType::of::<AldousBroder>()),
+        ("Hunt and Kill", GeneratorType::of::<HuntAndKill>()),
+        ("Growing Tree", GeneratorType::of::<GrowingTree>()),
+        ("Recursive Division", GeneratorType::of::<RecursiveDivision>()),
+        ("Binary Tree", GeneratorType::of::<BinaryTree>()),
+        ("Sidewinder", GeneratorType::of::<Sidewinder>()),
+        ("Eller", GeneratorType::of::<Eller>()),
+        ("Weave", GeneratorType::of::<Weave>()),
+    ]
+}
+
+fn post_processors() -> Vec<(&'static str, GeneratorType)> {
+    vec![
+        ("Braid", GeneratorType::of::<Braid>()),
+        ("Sparseness", GeneratorType::of::<Sparseness>()),
+    ]
+}
+
+fn solvers() -> Vec<(&'static str, SolverType)> {
+    vec![
+        ("Breadth First", SolverType::of::<BreadthFirst>()),
+        ("Depth First", SolverType::of::<DepthFirst>()),
+        ("A*", SolverType::of::<AStar>()),
+        ("Dead End Filling", SolverType::of::<DeadEndFilling>()),
+        ("Wall Follower", SolverType::of::<WallFollower>()),
+    ]
+}
+
 #[wasm_bindgen(start)]
 #[allow(dead_code)]
 pub fn start() {
@@ -208,7 +485,8 @@ pub fn start() {
 #[wasm_bindgen]
 #[allow(dead_code)]
 pub fn reset(canvas_data: &mut CanvasData) {
-    canvas_data.reset(Maze::new(canvas_data.maze.tiling, canvas_data.bounding_box, canvas_data.center, canvas_data.maze.scaling, canvas_data.maze.rotation));
+    canvas_data.seed = thread_rng().gen();
+    canvas_data.rebuild();
 }
 
 #[wasm_bindgen]
@@ -233,18 +511,370 @@ pub fn get_tilings() -> js_sys::Array {
 #[allow(dead_code)]
 pub fn set_tiling(tiling_name: String, canvas_data: &mut CanvasData) {
     let tiling = tilings().into_iter().find_map(|(tile_name, tiling)|if tiling_name == tile_name {Some(tiling)} else {None}).unwrap();
-    canvas_data.reset(Maze::new(tiling, canvas_data.bounding_box, canvas_data.center, canvas_data.maze.scaling, canvas_data.maze.rotation));
+    canvas_data.settings.tiling = tiling;
+    canvas_data.rebuild();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_generators() -> js_sys::Array {
+    let arr = js_sys::Array::new();
+    for (name, _) in generators().into_iter() {
+        arr.push(&JsValue::from_str(name));
+    }
+    arr
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_generator(generator_name: String, canvas_data: &mut CanvasData) {
+    canvas_data.generator_type = generators().into_iter().find_map(|(name, generator)|if generator_name == name {Some(generator)} else {None}).unwrap();
+    canvas_data.option_values.clear();
+    canvas_data.rebuild();
+}
+
+fn options_array(options: &[GeneratorOption], chosen: &HashMap<&'static str, usize>) -> js_sys::Array {
+    let values = resolve_options(options, chosen);
+
+    let arr = js_sys::Array::new();
+    for option in options {
+        let obj = js_sys::Object::new();
+        js_sys::Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str(option.name)).unwrap();
+        js_sys::Reflect::set(&obj, &JsValue::from_str("min"), &JsValue::from(option.min as u32)).unwrap();
+        js_sys::Reflect::set(&obj, &JsValue::from_str("default"), &JsValue::from(option.default as u32)).unwrap();
+        js_sys::Reflect::set(&obj, &JsValue::from_str("max"), &JsValue::from(option.max as u32)).unwrap();
+        js_sys::Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::from(values[option.name] as u32)).unwrap();
+        arr.push(&obj);
+    }
+    arr
+}
+
+// Finds the option being set and checks the value is in range
+fn checked_option(options: &[GeneratorOption], option_name: &str, value: usize) -> Result<&'static str, JsValue> {
+    let option = options.iter()
+        .find(|option| option.name == option_name)
+        .ok_or_else(|| JsValue::from_str(&format!("Unknown option {}", option_name)))?;
+
+    if value < option.min || value > option.max {
+        return Err(JsValue::from_str(&format!("{} must be between {} and {}", option.name, option.min, option.max)));
+    }
+
+    Ok(option.name)
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_generator_options(canvas_data: &CanvasData) -> js_sys::Array {
+    let options = (canvas_data.generator_type.options)(&canvas_data.maze);
+    options_array(&options, &canvas_data.option_values)
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_generator_option(option_name: String, value: usize, canvas_data: &mut CanvasData) -> Result<(), JsValue> {
+    let options = (canvas_data.generator_type.options)(&canvas_data.maze);
+    let name = checked_option(&options, &option_name, value)?;
+
+    canvas_data.option_values.insert(name, value);
+    canvas_data.rebuild();
+    Ok(())
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_post_processors() -> js_sys::Array {
+    let arr = js_sys::Array::new();
+    for (name, _) in post_processors().into_iter() {
+        arr.push(&JsValue::from_str(name));
+    }
+    arr
+}
+
+// Picking a post processor that does not exist turns post processing off
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_post_processor(post_processor_name: String, canvas_data: &mut CanvasData) {
+    canvas_data.post_processor_type = post_processors().into_iter().find_map(|(name, post_processor)|if post_processor_name == name {Some(post_processor)} else {None});
+    canvas_data.post_processor_values.clear();
+    canvas_data.rebuild();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_post_processor_options(canvas_data: &CanvasData) -> js_sys::Array {
+    match canvas_data.post_processor_type {
+        Some(post_processor_type) => options_array(&(post_processor_type.options)(&canvas_data.maze), &canvas_data.post_processor_values),
+        None => js_sys::Array::new(),
+    }
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_post_processor_option(option_name: String, value: usize, canvas_data: &mut CanvasData) -> Result<(), JsValue> {
+    let post_processor_type = canvas_data.post_processor_type.ok_or_else(|| JsValue::from_str("No post processor selected"))?;
+    let options = (post_processor_type.options)(&canvas_data.maze);
+    let name = checked_option(&options, &option_name, value)?;
+
+    canvas_data.post_processor_values.insert(name, value);
+    canvas_data.rebuild();
+    Ok(())
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_solvers() -> js_sys::Array {
+    let arr = js_sys::Array::new();
+    for (name, _) in solvers().into_iter() {
+        arr.push(&JsValue::from_str(name));
+    }
+    arr
+}
+
+// Picking a solver that does not exist turns solving off
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_solver(solver_name: String, canvas_data: &mut CanvasData) {
+    canvas_data.solver_type = solvers().into_iter().find_map(|(name, solver)|if solver_name == name {Some(solver)} else {None});
+    canvas_data.solver_values.clear();
+    canvas_data.reset_solver();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_solver_options(canvas_data: &CanvasData) -> js_sys::Array {
+    match canvas_data.solver_type {
+        Some(solver_type) => options_array(&(solver_type.options)(&canvas_data.maze), &canvas_data.solver_values),
+        None => js_sys::Array::new(),
+    }
+}
+
+// Like picking a solver, this keeps the maze and runs the solver on it again
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_solver_option(option_name: String, value: usize, canvas_data: &mut CanvasData) -> Result<(), JsValue> {
+    let solver_type = canvas_data.solver_type.ok_or_else(|| JsValue::from_str("No solver selected"))?;
+    let options = (solver_type.options)(&canvas_data.maze);
+    let name = checked_option(&options, &option_name, value)?;
+
+    canvas_data.solver_values.insert(name, value);
+    canvas_data.reset_solver();
+    Ok(())
 }
 
 #[wasm_bindgen]
 #[allow(dead_code)]
 pub fn set_rotation(rotation: f64, canvas_data: &mut CanvasData) {
-    canvas_data.reset(Maze::new(canvas_data.maze.tiling, canvas_data.bounding_box, canvas_data.center, canvas_data.maze.scaling, rotation));
+    canvas_data.settings.rotation = rotation;
+    canvas_data.rebuild();
 }
 
 #[wasm_bindgen]
 #[allow(dead_code)]
 pub fn set_scale(scale: f64, canvas_data: &mut CanvasData) {
     log::info!("Set Scale");
-    canvas_data.reset(Maze::new(canvas_data.maze.tiling, canvas_data.bounding_box, canvas_data.center, scale, canvas_data.maze.rotation));
+    canvas_data.settings.scaling = scale;
+    canvas_data.rebuild();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_seed(seed: u32, canvas_data: &mut CanvasData) {
+    canvas_data.seed = seed;
+    canvas_data.rebuild();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_seed(canvas_data: &CanvasData) -> u32 {
+    canvas_data.seed
+}
+
+fn offset_object(offset: Offset) -> js_sys::Object {
+    let obj = js_sys::Object::new();
+    js_sys::Reflect::set(&obj, &JsValue::from_str("x"), &JsValue::from(offset.x as i32)).unwrap();
+    js_sys::Reflect::set(&obj, &JsValue::from_str("y"), &JsValue::from(offset.y as i32)).unwrap();
+    obj
+}
+
+// Statistics for the finished maze, or null while it is still being generated or post processed
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_metrics(canvas_data: &CanvasData) -> JsValue {
+    if !canvas_data.finished || !canvas_data.post_processed {
+        return JsValue::NULL;
+    }
+
+    let metrics = Metrics::of(&canvas_data.maze);
+    let obj = js_sys::Object::new();
+    let set = |name: &str, value: JsValue| js_sys::Reflect::set(&obj, &JsValue::from_str(name), &value).unwrap();
+    set("cells", JsValue::from(metrics.cells as u32));
+    set("deadEnds", JsValue::from(metrics.dead_ends as u32));
+    set("deadEndRatio", JsValue::from(metrics.dead_end_ratio));
+    set("junctions", JsValue::from(metrics.junctions as u32));
+    set("diameter", JsValue::from(metrics.diameter as u32));
+    set("diameterEndpoints", match metrics.diameter_endpoints {
+        Some((first, second)) => js_sys::Array::of2(&offset_object(first), &offset_object(second)).into(),
+        None => JsValue::NULL,
+    });
+    set("averageCorridorLength", JsValue::from(metrics.average_corridor_length));
+    set("solutionLength", metrics.solution_length.map_or(JsValue::NULL, |length| JsValue::from(length as u32)));
+    set("solutionTurns", metrics.solution_turns.map_or(JsValue::NULL, |turns| JsValue::from(turns as u32)));
+    set("riverFactor", JsValue::from(metrics.river_factor));
+    obj.into()
+}
+
+// How many cells the boundary cut off from the rest of the maze, which are left out of it
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_dropped_cells(canvas_data: &CanvasData) -> usize {
+    canvas_data.maze.dropped
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_layers(layers: usize, canvas_data: &mut CanvasData) {
+    canvas_data.settings.layers = layers.max(1);
+    canvas_data.rebuild();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_layers(canvas_data: &CanvasData) -> usize {
+    canvas_data.settings.layers
+}
+
+// Switches which layer is drawn, without changing the maze
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_layer(layer: usize, canvas_data: &mut CanvasData) {
+    canvas_data.layer = layer.min(canvas_data.maze.layers - 1);
+    canvas_data.render();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_layer(canvas_data: &CanvasData) -> usize {
+    canvas_data.layer
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_wraps() -> js_sys::Array {
+    let arr = js_sys::Array::new();
+    for (name, _) in wraps().into_iter() {
+        arr.push(&JsValue::from_str(name));
+    }
+    arr
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_wrap(wrap_name: String, canvas_data: &mut CanvasData) {
+    let wrap = wraps().into_iter().find_map(|(name, wrap)|if wrap_name == name {Some(wrap)} else {None}).unwrap();
+    canvas_data.settings.wrap = wrap;
+    canvas_data.rebuild();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_boundaries() -> js_sys::Array {
+    let arr = js_sys::Array::new();
+    for (name, _) in boundaries(Rectangle { x: 0.0, y: 0.0, width: 1.0, height: 1.0 }).into_iter() {
+        arr.push(&JsValue::from_str(name));
+    }
+    arr
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_boundary(boundary_name: String, canvas_data: &mut CanvasData) {
+    canvas_data.boundary = boundaries(canvas_data.bounding_box).into_iter().find_map(|(name, boundary)|if boundary_name == name {Some(boundary)} else {None}).unwrap();
+    canvas_data.rebuild();
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn get_inclusions() -> js_sys::Array {
+    let arr = js_sys::Array::new();
+    for (name, _) in inclusions().into_iter() {
+        arr.push(&JsValue::from_str(name));
+    }
+    arr
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_inclusion(inclusion_name: String, canvas_data: &mut CanvasData) {
+    let inclusion = inclusions().into_iter().find_map(|(name, inclusion)|if inclusion_name == name {Some(inclusion)} else {None}).unwrap();
+    canvas_data.settings.inclusion = inclusion;
+    canvas_data.rebuild();
+}
+
+// Bitmaps keep the cells whose centers land on a set pixel, as their strokes are often too thin for whole cells to fit
+fn set_mask(bitmap: Bitmap, canvas_data: &mut CanvasData) -> Result<(), JsValue> {
+    if bitmap.is_blank() {
+        return Err(JsValue::from_str("The mask has no pixels set"));
+    }
+
+    let boundary = Box::new(bitmap.fit(canvas_data.bounding_box));
+    let settings = MazeSettings { inclusion: Inclusion::Centroid, ..canvas_data.settings };
+    let maze = Maze::new(settings, boundary.as_ref(), canvas_data.center);
+    if maze.cells.is_empty() {
+        return Err(JsValue::from_str("The mask is too fine for any cells to fit, try a smaller scale"));
+    }
+
+    canvas_data.boundary = boundary;
+    canvas_data.settings = settings;
+    canvas_data.reset(maze);
+    Ok(())
+}
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_text_mask(text: String, canvas_data: &mut CanvasData) -> Result<(), JsValue> {
+    set_mask(Bitmap::text(&text, true), canvas_data)
+}
+
+// Takes one bit per pixel, with every row starting on a new byte
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_bitmap_mask(width: usize, height: usize, bytes: &[u8], canvas_data: &mut CanvasData) -> Result<(), JsValue> {
+    let bitmap = Bitmap::from_bytes(width, height, bytes).map_err(|error| JsValue::from_str(&error.message))?;
+    set_mask(bitmap, canvas_data)
+}
+
+// Takes the pixels of a decoded image, four bytes each
+#[wasm_bindgen]
+#[allow(dead_code)]
+pub fn set_image_mask(width: usize, height: usize, rgba: &[u8], canvas_data: &mut CanvasData) -> Result<(), JsValue> {
+    let bitmap = Bitmap::from_rgba(width, height, rgba).map_err(|error| JsValue::from_str(&error.message))?;
+    set_mask(bitmap, canvas_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_maze_finishes() {
+        let area = Rectangle { x: 0.0, y: 0.0, width: 500.0, height: 500.0 };
+        let settings = MazeSettings { tiling: tilings::TETRAKIS, scaling: 100.0, rotation: 0.0, layers: 1, wrap: Wrap::None, inclusion: Inclusion::AllCorners };
+        let maze = Maze::new(settings, &area, Coordinates { x: 250.0, y: 250.0 });
+        assert!(maze.cells.is_empty());
+        assert!(endpoints(&maze).is_none());
+
+        for (name, generator_type) in generators() {
+            let options = resolve_options(&(generator_type.options)(&maze), &HashMap::new());
+            let mut generator = (generator_type.init)(&maze, StdRng::seed_from_u64(0), options);
+            let finished = (0..10).any(|_| generator.step(&maze).iter().any(|update| matches!(update, GeneratorUpdate::Finished())));
+            assert!(finished, "{}", name);
+        }
+
+        for (name, post_processor_type) in post_processors() {
+            let options = resolve_options(&(post_processor_type.options)(&maze), &HashMap::new());
+            let mut post_processor = (post_processor_type.init)(&maze, StdRng::seed_from_u64(0), options);
+            let finished = (0..10).any(|_| post_processor.step(&maze).iter().any(|update| matches!(update, GeneratorUpdate::Finished())));
+            assert!(finished, "{}", name);
+        }
+    }
 }
@@ -7,31 +7,37 @@ pub const HEX: Tiling = Tiling {
         Offset {
             x: 0,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: -SQRT_3 },
         },
         Offset {
             x: 1,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: 1.5, y: -SQRT_3 / 2.0 },
         },
         Offset {
             x: 1,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: 1.5, y: SQRT_3 / 2.0 },
         },
         Offset {
             x: 0,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: SQRT_3 },
         },
         Offset {
             x: -1,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: -1.5, y: SQRT_3 / 2.0 },
         },
         Offset {
             x: -1,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: -1.5, y: -SQRT_3 / 2.0 },
         },
     ],
@@ -40,6 +46,7 @@ pub const HEX: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -91,21 +98,25 @@ pub const TRIANGLE: Tiling = Tiling {
         Offset {
             x: -1,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: -1.0, y: -SQRT_3 },
         },
         Offset {
             x: 1,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: 1.0, y: -SQRT_3 },
         },
         Offset {
             x: 1,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: 1.0, y: SQRT_3 },
         },
         Offset {
             x: -1,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: -1.0, y: SQRT_3 },
         },
     ],
@@ -114,6 +125,7 @@ pub const TRIANGLE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: -1,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: -SQRT_3 },
             },
             corners: &[
@@ -143,6 +155,7 @@ pub const TRIANGLE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -176,21 +189,25 @@ pub const SQUARE: Tiling = Tiling {
         Offset {
             x: 0,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: -1.0 },
         },
         Offset {
             x: 1,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: 1.0, y: 0.0 },
         },
         Offset {
             x: 0,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: 1.0 },
         },
         Offset {
             x: -1,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: -1.0, y: 0.0 },
         },
     ],
@@ -199,6 +216,7 @@ pub const SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -238,31 +256,37 @@ pub const TRUNCATED_SQUARE: Tiling = Tiling {
         Offset {
             x: 0,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: -2.0 },
         },
         Offset {
             x: 2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: 2.0, y: 0.0 },
         },
         Offset {
             x: 0,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: 2.0 },
         },
         Offset {
             x: -2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: -2.0, y: 0.0 },
         },
         Offset {
             x: -2,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: -2.0, y: -2.0 },
         },
         Offset {
             x: 2,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: 2.0, y: 2.0 },
         },
     ],
@@ -271,6 +295,7 @@ pub const TRUNCATED_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -330,6 +355,7 @@ pub const TRUNCATED_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 1.0, y: 1.0 },
             },
             corners: &[
@@ -369,21 +395,25 @@ pub const TETRAKIS_SQUARE: Tiling = Tiling {
         Offset {
             x: 0,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: -2.0 },
         },
         Offset {
             x: 2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: 2.0, y: 0.0 },
         },
         Offset {
             x: 0,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: 2.0 },
         },
         Offset {
             x: -2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: -2.0, y: 0.0 },
         },
     ],
@@ -392,6 +422,7 @@ pub const TETRAKIS_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: -0.5 },
             },
             corners: &[
@@ -421,6 +452,7 @@ pub const TETRAKIS_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.5, y: 0.0 },
             },
             corners: &[
@@ -450,6 +482,7 @@ pub const TETRAKIS_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.5 },
             },
             corners: &[
@@ -479,6 +512,7 @@ pub const TETRAKIS_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: -0.5, y: 0.0 },
             },
             corners: &[
@@ -512,21 +546,25 @@ pub const SNUB_SQUARE: Tiling = Tiling {
         Offset {
             x: 1,
             y: -3,
+            layer: 0,
             coordinates: Coordinates { x: 1.0, y: -2.0 - SQRT_3 },
         },
         Offset {
             x: 3,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: 2.0 + SQRT_3, y: 1.0 },
         },
         Offset {
             x: -1,
             y: 3,
+            layer: 0,
             coordinates: Coordinates { x: -1.0, y: 2.0 + SQRT_3 },
         },
         Offset {
             x: -3,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: -2.0 - SQRT_3, y: -1.0 },
         },
     ],
@@ -535,6 +573,7 @@ pub const SNUB_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -570,6 +609,7 @@ pub const SNUB_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 1.0, y: 0.0 },
             },
             corners: &[
@@ -599,6 +639,7 @@ pub const SNUB_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 2,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 1.0, y: 1.0 },
             },
             corners: &[
@@ -628,6 +669,7 @@ pub const SNUB_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: -1.0, y: 1.0 },
             },
             corners: &[
@@ -657,6 +699,7 @@ pub const SNUB_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 1.0 },
             },
             corners: &[
@@ -686,6 +729,7 @@ pub const SNUB_SQUARE: Tiling = Tiling {
             offset: Offset {
                 x: 2,
                 y: 2,
+                layer: 0,
                 coordinates: Coordinates { x: 1.0, y: 2.0 },
             },
             corners: &[
@@ -725,21 +769,25 @@ pub const CAIRO_PENTAGONAL: Tiling = Tiling {
         Offset {
             x: -2,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: -2.0, y: -1.0 },
         },
         Offset {
             x: 1,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 1.0, y: -2.0 },
         },
         Offset {
             x: 2,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: 2.0, y: 1.0 },
         },
         Offset {
             x: -1,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: -1.0, y: 2.0 },
         },
     ],
@@ -748,6 +796,7 @@ pub const CAIRO_PENTAGONAL: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: -0.5 },
             },
             corners: &[
@@ -789,6 +838,7 @@ pub const CAIRO_PENTAGONAL: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.5, y: 0.0 },
             },
             corners: &[
@@ -830,6 +880,7 @@ pub const CAIRO_PENTAGONAL: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.5 },
             },
             corners: &[
@@ -871,6 +922,7 @@ pub const CAIRO_PENTAGONAL: Tiling = Tiling {
             offset: Offset {
                 x: -1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: -0.5, y: 0.0 },
             },
             corners: &[
@@ -916,31 +968,37 @@ pub const TRIHEXAGONAL: Tiling = Tiling {
         Offset {
             x: 0,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: -2.0 },
         },
         Offset {
             x: 2,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: SQRT_3, y: -1.0 },
         },
         Offset {
             x: 2,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: SQRT_3, y: 1.0 },
         },
         Offset {
             x: 0,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: 2.0 },
         },
         Offset {
             x: -2,
             y: 1,
+            layer: 0,
             coordinates: Coordinates { x: -SQRT_3, y: 1.0 },
         },
         Offset {
             x: -2,
             y: -1,
+            layer: 0,
             coordinates: Coordinates { x: -SQRT_3, y: -1.0 },
         },
     ],
@@ -949,6 +1007,7 @@ pub const TRIHEXAGONAL: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -996,6 +1055,7 @@ pub const TRIHEXAGONAL: Tiling = Tiling {
             offset: Offset {
                 x: -1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: -SQRT_3/2.0, y: 0.5 },
             },
             corners: &[
@@ -1025,6 +1085,7 @@ pub const TRIHEXAGONAL: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: SQRT_3/2.0, y: 0.5 },
             },
             corners: &[
@@ -1058,31 +1119,37 @@ pub const RHOMBILLE: Tiling = Tiling {
         Offset {
             x: 0,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: -2.0*SQRT_3 },
         },
         Offset {
             x: 2,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 3.0, y: -SQRT_3 },
         },
         Offset {
             x: 2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: 3.0, y: SQRT_3 },
         },
         Offset {
             x: 0,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: 2.0*SQRT_3 },
         },
         Offset {
             x: -2,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: -3.0, y: SQRT_3 },
         },
         Offset {
             x: -2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: -3.0, y: -SQRT_3 },
         },
     ],
@@ -1091,6 +1158,7 @@ pub const RHOMBILLE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: -SQRT_3 },
             },
             corners: &[
@@ -1126,6 +1194,7 @@ pub const RHOMBILLE: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: SQRT_3 },
             },
             corners: &[
@@ -1161,6 +1230,7 @@ pub const RHOMBILLE: Tiling = Tiling {
             offset: Offset {
                 x: -1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: -1.0, y: 0.0 },
             },
             corners: &[
@@ -1200,31 +1270,37 @@ pub const TRUNCATED_HEX: Tiling = Tiling {
         Offset {
             x: 0,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: -2.0 - SQRT_3 },
         },
         Offset {
             x: 2,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 1.5 + SQRT_3, y: -1.0 - 0.5*SQRT_3 },
         },
         Offset {
             x: 2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: 1.5 + SQRT_3, y: 1.0 + 0.5*SQRT_3 },
         },
         Offset {
             x: 0,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: 0.0, y: 2.0 + SQRT_3 },
         },
         Offset {
             x: -2,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: -1.5 - SQRT_3, y: 1.0 + 0.5*SQRT_3 },
         },
         Offset {
             x: -2,
             y: 0,
+            layer: 0,
             coordinates: Coordinates { x: -1.5 - SQRT_3, y: -1.0 - 0.5*SQRT_3 },
         },
     ],
@@ -1233,6 +1309,7 @@ pub const TRUNCATED_HEX: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -1316,6 +1393,7 @@ pub const TRUNCATED_HEX: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: -0.5, y: 1.0 + 0.5*SQRT_3 },
             },
             corners: &[
@@ -1345,6 +1423,7 @@ pub const TRUNCATED_HEX: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 0.5, y: 1.0 + 0.5*SQRT_3 },
             },
             corners: &[
@@ -1378,21 +1457,25 @@ pub const TETRAKIS: Tiling = Tiling {
         Offset {
             x: -3,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: -3.0, y: -SQRT_3 },
         },
         Offset {
             x: 3,
             y: -2,
+            layer: 0,
             coordinates: Coordinates { x: 3.0, y: -SQRT_3 },
         },
         Offset {
             x: 3,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: 3.0, y: SQRT_3 },
         },
         Offset {
             x: -3,
             y: 2,
+            layer: 0,
             coordinates: Coordinates { x: -3.0, y: SQRT_3 },
         },
     ],
@@ -1401,6 +1484,7 @@ pub const TETRAKIS: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -1430,6 +1514,7 @@ pub const TETRAKIS: Tiling = Tiling {
             offset: Offset {
                 x: 0,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: -1.0, y: SQRT_3 },
             },
             corners: &[
@@ -1459,6 +1544,7 @@ pub const TETRAKIS: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -1488,6 +1574,7 @@ pub const TETRAKIS: Tiling = Tiling {
             offset: Offset {
                 x: 1,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -1517,6 +1604,7 @@ pub const TETRAKIS: Tiling = Tiling {
             offset: Offset {
                 x: 2,
                 y: 0,
+                layer: 0,
                 coordinates: Coordinates { x: 0.0, y: 0.0 },
             },
             corners: &[
@@ -1546,6 +1634,7 @@ pub const TETRAKIS: Tiling = Tiling {
             offset: Offset {
                 x: 2,
                 y: 1,
+                layer: 0,
                 coordinates: Coordinates { x: 1.0, y: SQRT_3 },
             },
             corners: &[
@@ -6,16 +6,36 @@ var canvas = document.getElementById("canvas")
 let data = wasm.wasm_init(canvas, 15, 0);
 
 var tilingElement = document.getElementById("select-tiling");
+var generatorElement = document.getElementById("select-generator");
+var generatorOptionsElement = document.getElementById("generator-options");
+var postProcessorElement = document.getElementById("select-post-processor");
+var postProcessorOptionsElement = document.getElementById("post-processor-options");
+var solverElement = document.getElementById("select-solver");
+var solverOptionsElement = document.getElementById("solver-options");
 var runElement = document.getElementById("run");
 var stopElement = document.getElementById("stop");
 var stepElement = document.getElementById("step");
 var speedElement = document.getElementById("speed");
 var rotationElement = document.getElementById("rotation");
 var scaleElement = document.getElementById("scale");
+var seedElement = document.getElementById("seed");
+var boundaryElement = document.getElementById("select-boundary");
+var inclusionElement = document.getElementById("select-inclusion");
+var maskTextElement = document.getElementById("mask-text");
+var maskTextApplyElement = document.getElementById("mask-text-apply");
+var maskImageElement = document.getElementById("mask-image");
+var wrapElement = document.getElementById("select-wrap");
+var layersElement = document.getElementById("layers");
+var layerElement = document.getElementById("layer");
+var layerLabelElement = document.getElementById("layer-label");
+var metricsElement = document.getElementById("metrics");
+var droppedCellsElement = document.getElementById("dropped-cells");
 var interval = null;
 speedElement.value = 50;
 scaleElement.value = 15;
 rotationElement.value = 0;
+layersElement.value = wasm.get_layers(data);
+seedElement.value = wasm.get_seed(data);
 var running = false;
 var finished = false;
 
@@ -29,6 +49,116 @@ for (var tiling of wasm.get_tilings()) {
 tilingElement.onchange = function() {
     set_running(false);
     wasm.set_tiling(this.value, data);
+    render_all_options();
+    render_metrics();
+}
+
+for (var generator of wasm.get_generators()) {
+    var option = document.createElement("option");
+    option.text = generator;
+    option.value = generator;
+    generatorElement.add(option);
+}
+
+generatorElement.onchange = function() {
+    set_running(false);
+    finished = false;
+    wasm.set_generator(this.value, data);
+    render_all_options();
+    render_metrics();
+}
+
+for (var postProcessor of wasm.get_post_processors()) {
+    var option = document.createElement("option");
+    option.text = postProcessor;
+    option.value = postProcessor;
+    postProcessorElement.add(option);
+}
+
+postProcessorElement.onchange = function() {
+    set_running(false);
+    finished = false;
+    wasm.set_post_processor(this.value, data);
+    render_all_options();
+    render_metrics();
+}
+
+for (var solver of wasm.get_solvers()) {
+    var option = document.createElement("option");
+    option.text = solver;
+    option.value = solver;
+    solverElement.add(option);
+}
+
+solverElement.onchange = function() {
+    set_running(false);
+    // Keep the maze so the new solver runs on it
+    finished = false;
+    wasm.set_solver(this.value, data);
+    render_all_options();
+}
+
+function render_options(element, options, set_option) {
+    element.replaceChildren();
+    for (let option of options) {
+        let label = document.createElement("label");
+        label.textContent = option.name + ": " + option.value;
+        let input = document.createElement("input");
+        input.type = "range";
+        input.min = option.min;
+        input.max = option.max;
+        input.value = option.value;
+        input.className = "generator-option";
+        input.oninput = function() {
+            set_running(false);
+            finished = false;
+            try {
+                set_option(option.name, parseInt(this.value), data);
+                label.textContent = option.name + ": " + this.value;
+                render_metrics();
+            } catch (error) {
+                console.error(error);
+            }
+        }
+        element.appendChild(label);
+        element.appendChild(input);
+    }
+}
+
+// The limits of some options depend on the maze, so these are rendered again whenever it changes
+function render_all_options() {
+    render_options(generatorOptionsElement, wasm.get_generator_options(data), wasm.set_generator_option);
+    render_options(postProcessorOptionsElement, wasm.get_post_processor_options(data), wasm.set_post_processor_option);
+    render_options(solverOptionsElement, wasm.get_solver_options(data), wasm.set_solver_option);
+}
+
+render_all_options();
+
+function render_metrics() {
+    let dropped = wasm.get_dropped_cells(data);
+    droppedCellsElement.textContent = dropped > 0 ? dropped + " cells cut off by the edge were left out" : "";
+
+    metricsElement.replaceChildren();
+    let metrics = wasm.get_metrics(data);
+    if (metrics == null) {
+        return;
+    }
+
+    let rows = [
+        ["Cells", metrics.cells],
+        ["Dead ends", metrics.deadEnds + " (" + (metrics.deadEndRatio * 100).toFixed(1) + "%)"],
+        ["Junctions", metrics.junctions],
+        ["Diameter", metrics.diameter],
+        ["Average corridor", metrics.averageCorridorLength.toFixed(2)],
+        ["Solution length", metrics.solutionLength ?? "-"],
+        ["Solution turns", metrics.solutionTurns ?? "-"],
+        ["River factor", metrics.riverFactor.toFixed(2)],
+    ];
+    for (let [name, value] of rows) {
+        let row = metricsElement.insertRow();
+        row.insertCell().textContent = name;
+        row.insertCell().textContent = value;
+    }
 }
 
 run.onclick = function() {
@@ -39,15 +169,31 @@ function set_running(bool) {
     if (bool && finished) {
         finished = false;
         wasm.reset(data);
+        seedElement.value = wasm.get_seed(data);
     }
 
     tilingElement.disabled = bool;
+    generatorElement.disabled = bool;
+    solverElement.disabled = bool;
+    postProcessorElement.disabled = bool;
+    for (let input of document.getElementsByClassName("generator-option")) {
+        input.disabled = bool;
+    }
     stepElement.disabled = bool;
     runElement.disabled = bool;
     scaleElement.disabled = bool;
     rotationElement.disabled = bool;
+    seedElement.disabled = bool;
+    layersElement.disabled = bool;
+    boundaryElement.disabled = bool;
+    inclusionElement.disabled = bool;
+    maskTextElement.disabled = bool;
+    maskTextApplyElement.disabled = bool;
+    maskImageElement.disabled = bool;
+    wrapElement.disabled = bool;
     stopElement.disabled = !bool;    
     running = bool;
+    render_metrics();
     if (bool) {
         let timeout = 0;
         let iterations = 1;
@@ -80,6 +226,7 @@ stopElement.onclick = function() {
 
 stepElement.onclick = function() {
     wasm.step(data, 1);
+    render_metrics();
 }
 
 speedElement.oninput = function() {
@@ -91,9 +238,123 @@ speedElement.oninput = function() {
 scaleElement.oninput = function() {
     var scale = parseInt(this.value);
     wasm.set_scale(scale, data);
+    render_all_options();
+    render_metrics();
 }
 
 rotationElement.oninput = function() {
     var rotation = parseInt(this.value);
     wasm.set_rotation(rotation, data);
-}
\ No newline at end of file
+    render_all_options();
+    render_metrics();
+}
+
+for (var boundary of wasm.get_boundaries()) {
+    var option = document.createElement("option");
+    option.text = boundary;
+    option.value = boundary;
+    boundaryElement.add(option);
+}
+
+boundaryElement.onchange = function() {
+    set_running(false);
+    finished = false;
+    wasm.set_boundary(this.value, data);
+    render_all_options();
+    render_metrics();
+}
+
+for (var inclusion of wasm.get_inclusions()) {
+    var option = document.createElement("option");
+    option.text = inclusion;
+    option.value = inclusion;
+    inclusionElement.add(option);
+}
+
+inclusionElement.onchange = function() {
+    set_running(false);
+    finished = false;
+    wasm.set_inclusion(this.value, data);
+    render_all_options();
+    render_metrics();
+}
+
+// Masks always keep the cells whose centers are on the mask, and aren't one of the listed shapes
+function apply_mask(set_mask) {
+    set_running(false);
+    finished = false;
+    try {
+        set_mask();
+        boundaryElement.selectedIndex = -1;
+        inclusionElement.value = "Centroid";
+        render_all_options();
+        render_metrics();
+    } catch (error) {
+        console.error(error);
+    }
+}
+
+maskTextApplyElement.onclick = function() {
+    apply_mask(() => wasm.set_text_mask(maskTextElement.value, data));
+}
+
+maskImageElement.onchange = async function() {
+    if (this.files.length == 0) {
+        return;
+    }
+    // Let the browser decode the image, then hand its pixels over
+    let image = await createImageBitmap(this.files[0]);
+    let context = new OffscreenCanvas(image.width, image.height).getContext("2d");
+    context.drawImage(image, 0, 0);
+    let pixels = context.getImageData(0, 0, image.width, image.height);
+    apply_mask(() => wasm.set_image_mask(image.width, image.height, new Uint8Array(pixels.data.buffer), data));
+}
+
+for (var wrap of wasm.get_wraps()) {
+    var option = document.createElement("option");
+    option.text = wrap;
+    option.value = wrap;
+    wrapElement.add(option);
+}
+
+wrapElement.onchange = function() {
+    set_running(false);
+    finished = false;
+    wasm.set_wrap(this.value, data);
+    render_all_options();
+    render_metrics();
+}
+
+function render_layer() {
+    layerElement.max = wasm.get_layers(data);
+    layerElement.value = wasm.get_layer(data) + 1;
+    layerLabelElement.textContent = "Showing Layer " + layerElement.value;
+}
+
+render_layer();
+
+layersElement.oninput = function() {
+    set_running(false);
+    finished = false;
+    wasm.set_layers(parseInt(this.value), data);
+    render_layer();
+    render_all_options();
+    render_metrics();
+}
+
+// Only changes what is drawn, so it works while the maze is being generated
+layerElement.oninput = function() {
+    wasm.set_layer(parseInt(this.value) - 1, data);
+    render_layer();
+}
+
+seedElement.onchange = function() {
+    var seed = parseInt(this.value);
+    if (isNaN(seed)) {
+        return;
+    }
+    finished = false;
+    wasm.set_seed(seed >>> 0, data);
+    this.value = wasm.get_seed(data);
+    render_metrics();
+}